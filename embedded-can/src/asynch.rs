@@ -0,0 +1,21 @@
+//! Async CAN API
+
+#![allow(async_fn_in_trait)]
+
+/// An async CAN interface receiving frames through a FIFO.
+///
+/// This is the asynchronous version of [`nb::CanReceiveFifo`](crate::nb::CanReceiveFifo).
+/// Frames are returned in reception order.
+pub trait CanReceiveFifo {
+    /// Associated frame type.
+    type Frame: crate::Frame;
+
+    /// Associated error type.
+    type Error: crate::Error;
+
+    /// Waits until a frame is available, then removes it from the FIFO and returns it.
+    async fn receive(&mut self) -> Result<Self::Frame, Self::Error>;
+
+    /// Sets the behavior of the FIFO when a frame is received while it is full.
+    fn set_fifo_mode(&mut self, mode: crate::FifoMode) -> Result<(), Self::Error>;
+}
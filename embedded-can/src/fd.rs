@@ -0,0 +1,120 @@
+//! CAN FD configuration and data length codes.
+
+/// CAN FD bit timing configuration.
+///
+/// CAN FD frames with the bit rate switch (BRS) bit set transmit the data phase at
+/// `data_bitrate_hz`, while the arbitration phase always uses `arbitration_bitrate_hz`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub struct CanFdConfig {
+    /// Bit rate of the arbitration phase, in Hz.
+    pub arbitration_bitrate_hz: u32,
+    /// Bit rate of the data phase, in Hz.
+    pub data_bitrate_hz: u32,
+    /// Sample point of the arbitration phase, in percent of the bit time.
+    pub sample_point_arbitration: u8,
+    /// Sample point of the data phase, in percent of the bit time.
+    pub sample_point_data: u8,
+}
+
+/// Configure the CAN FD mode of a CAN controller.
+///
+/// This is separate from the frame traits, so that controllers only supporting classic CAN
+/// don't need to implement it.
+pub trait ConfigureCanFd {
+    /// Associated error type.
+    type Error: crate::Error;
+
+    /// Enables CAN FD operation with the given bit timing.
+    ///
+    /// Implementations pick the bit timing closest to the requested one, and return an error if
+    /// the configuration can't be achieved with the controller clock (e.g. a data bit rate above
+    /// the supported maximum, or a sample point that can't be represented).
+    fn configure_fd(&mut self, config: &CanFdConfig) -> Result<(), Self::Error>;
+
+    /// Returns `true` if CAN FD operation is enabled.
+    fn is_fd_enabled(&mut self) -> Result<bool, Self::Error>;
+}
+
+/// Data lengths of CAN FD frames for the data length codes 9 to 15.
+const FD_LENGTHS: [u8; 7] = [12, 16, 20, 24, 32, 48, 64];
+
+/// Returns `true` if `dlc` is a valid CAN FD data length code, i.e. in the range 0..=15.
+pub const fn is_valid_fd_dlc(dlc: u8) -> bool {
+    dlc <= 15
+}
+
+/// Converts a data length code (DLC) to the data length in bytes.
+///
+/// DLC 0 to 8 map to the same number of bytes, as in classic CAN. DLC 9 to 15 map to the
+/// CAN FD data lengths 12, 16, 20, 24, 32, 48 and 64.
+///
+/// Returns `None` if `dlc` is greater than 15.
+///
+/// ```
+/// use embedded_can::dlc_to_len;
+///
+/// assert_eq!(dlc_to_len(8), Some(8));
+/// assert_eq!(dlc_to_len(9), Some(12));
+/// assert_eq!(dlc_to_len(15), Some(64));
+/// assert_eq!(dlc_to_len(16), None);
+/// ```
+pub const fn dlc_to_len(dlc: u8) -> Option<usize> {
+    match dlc {
+        0..=8 => Some(dlc as usize),
+        9..=15 => Some(FD_LENGTHS[dlc as usize - 9] as usize),
+        _ => None,
+    }
+}
+
+/// Converts a data length in bytes to the data length code (DLC).
+///
+/// This is the inverse of [`dlc_to_len`]. Returns `None` if no DLC encodes exactly `len` bytes:
+/// CAN FD payloads whose length is not one of the FD data lengths must be padded to the next one
+/// before being sent.
+///
+/// ```
+/// use embedded_can::len_to_dlc;
+///
+/// assert_eq!(len_to_dlc(8), Some(8));
+/// assert_eq!(len_to_dlc(12), Some(9));
+/// assert_eq!(len_to_dlc(13), None);
+/// assert_eq!(len_to_dlc(64), Some(15));
+/// ```
+pub const fn len_to_dlc(len: usize) -> Option<u8> {
+    if len <= 8 {
+        return Some(len as u8);
+    }
+    let mut i = 0;
+    while i < FD_LENGTHS.len() {
+        if FD_LENGTHS[i] as usize == len {
+            return Some(i as u8 + 9);
+        }
+        i += 1;
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dlc_len_roundtrip() {
+        for dlc in 0..=15 {
+            assert!(is_valid_fd_dlc(dlc));
+            let len = dlc_to_len(dlc).unwrap();
+            assert_eq!(len_to_dlc(len), Some(dlc));
+        }
+    }
+
+    #[test]
+    fn invalid_dlc_and_len() {
+        assert!(!is_valid_fd_dlc(16));
+        assert_eq!(dlc_to_len(16), None);
+        assert_eq!(dlc_to_len(u8::MAX), None);
+        assert_eq!(len_to_dlc(9), None);
+        assert_eq!(len_to_dlc(63), None);
+        assert_eq!(len_to_dlc(65), None);
+    }
+}
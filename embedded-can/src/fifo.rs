@@ -0,0 +1,11 @@
+//! CAN receive FIFO configuration.
+
+/// Behavior of a receive FIFO when a frame is received while it is full.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub enum FifoMode {
+    /// The oldest frame in the FIFO is dropped to make room for the new one.
+    Overwrite,
+    /// The FIFO is locked: new frames are dropped until a frame is received from the FIFO.
+    Lock,
+}
@@ -0,0 +1,279 @@
+//! CAN acceptance filter configuration.
+
+use crate::{ExtendedId, Id, StandardId};
+
+/// Configuration of a CAN acceptance filter.
+///
+/// Use the convenience constructors or [`CanFilterBuilder`] to create a filter, and
+/// [`CanFilterConfig::set_filter`] to apply it to a controller.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[non_exhaustive]
+pub enum FilterConfig {
+    /// Accept all frames, with standard or extended identifiers.
+    AcceptAll,
+    /// Accept frames whose identifier matches `id` in all bits set in `mask`.
+    ///
+    /// Only identifiers of the same type (standard or extended) as `id` are accepted. For
+    /// standard identifiers, `mask` is in the range `0..=0x7FF`.
+    Mask {
+        /// Identifier to match.
+        id: Id,
+        /// Bits of the identifier that must match `id`.
+        mask: u32,
+    },
+    /// Accept frames whose identifier is in the range `low..=high`.
+    ///
+    /// `low` and `high` are of the same type (standard or extended), and only identifiers of this
+    /// type are accepted.
+    Range {
+        /// Lowest accepted identifier.
+        low: Id,
+        /// Highest accepted identifier.
+        high: Id,
+    },
+}
+
+impl FilterConfig {
+    /// Creates a filter accepting all frames.
+    #[inline]
+    #[must_use]
+    pub const fn accept_all() -> Self {
+        Self::AcceptAll
+    }
+
+    /// Creates a filter accepting only frames with the identifier `id`.
+    #[inline]
+    #[must_use]
+    pub fn exact_match(id: impl Into<Id>) -> Self {
+        let id = id.into();
+        Self::Mask {
+            id,
+            mask: full_mask(&id),
+        }
+    }
+
+    /// Creates a filter accepting frames with identifiers in the range `low..=high`.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, panics if `low` and `high` are not of the same type, or if `low` is
+    /// greater than `high`.
+    #[inline]
+    #[must_use]
+    pub fn range(low: impl Into<Id>, high: impl Into<Id>) -> Self {
+        let (low, high) = (low.into(), high.into());
+        debug_assert!(
+            is_extended(&low) == is_extended(&high),
+            "range bounds must be both standard or both extended identifiers"
+        );
+        debug_assert!(
+            raw(&low) <= raw(&high),
+            "range low bound is above high bound"
+        );
+        Self::Range { low, high }
+    }
+
+    /// Returns `true` if a frame with the identifier `id` passes this filter.
+    ///
+    /// This can be used to filter frames in software, for example when the controller has no
+    /// filter bank left.
+    #[must_use]
+    pub fn matches(&self, id: Id) -> bool {
+        match self {
+            Self::AcceptAll => true,
+            Self::Mask { id: filter, mask } => {
+                is_extended(filter) == is_extended(&id) && (raw(filter) ^ raw(&id)) & mask == 0
+            }
+            Self::Range { low, high } => {
+                is_extended(low) == is_extended(&id) && (raw(low)..=raw(high)).contains(&raw(&id))
+            }
+        }
+    }
+}
+
+/// Builder for mask-based [`FilterConfig`]s.
+///
+/// ```
+/// use embedded_can::{CanFilterBuilder, FilterConfig, Id, StandardId};
+///
+/// let filter = CanFilterBuilder::new()
+///     .id(StandardId::new(0x123).unwrap())
+///     .mask_standard_id(0x7F0)
+///     .build();
+///
+/// assert!(filter.matches(Id::Standard(StandardId::new(0x12A).unwrap())));
+/// assert!(!filter.matches(Id::Standard(StandardId::new(0x133).unwrap())));
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub struct CanFilterBuilder {
+    id: Id,
+    mask: Option<u32>,
+}
+
+impl CanFilterBuilder {
+    /// Creates a new builder.
+    ///
+    /// By default, the filter matches standard identifier `0` exactly.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            id: Id::Standard(StandardId::ZERO),
+            mask: None,
+        }
+    }
+
+    /// Sets the identifier to match.
+    #[inline]
+    #[must_use]
+    pub fn id(mut self, id: impl Into<Id>) -> Self {
+        self.id = id.into();
+        self
+    }
+
+    /// Sets the mask for a standard identifier.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, panics if the identifier is extended or if `mask` is greater than `0x7FF`.
+    #[inline]
+    #[must_use]
+    pub fn mask_standard_id(mut self, mask: u16) -> Self {
+        debug_assert!(
+            !is_extended(&self.id),
+            "standard mask used with an extended identifier"
+        );
+        debug_assert!(
+            mask <= StandardId::MAX.as_raw(),
+            "standard mask out of range"
+        );
+        self.mask = Some(mask.into());
+        self
+    }
+
+    /// Sets the mask for an extended identifier.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, panics if the identifier is standard or if `mask` is greater than
+    /// `0x1FFF_FFFF`.
+    #[inline]
+    #[must_use]
+    pub fn mask_extended_id(mut self, mask: u32) -> Self {
+        debug_assert!(
+            is_extended(&self.id),
+            "extended mask used with a standard identifier"
+        );
+        debug_assert!(
+            mask <= ExtendedId::MAX.as_raw(),
+            "extended mask out of range"
+        );
+        self.mask = Some(mask);
+        self
+    }
+
+    /// Builds the filter.
+    ///
+    /// If no mask was set, the filter matches the identifier exactly.
+    #[inline]
+    #[must_use]
+    pub fn build(self) -> FilterConfig {
+        let full = full_mask(&self.id);
+        FilterConfig::Mask {
+            id: self.id,
+            mask: self.mask.map_or(full, |mask| mask & full),
+        }
+    }
+}
+
+impl Default for CanFilterBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Configure the acceptance filters of a CAN controller.
+pub trait CanFilterConfig {
+    /// Associated error type.
+    type Error: crate::Error;
+
+    /// Returns the number of filters the controller provides.
+    fn filter_count(&self) -> usize;
+
+    /// Sets the filter at `index` to `filter`.
+    ///
+    /// Returns an error if `index` is out of range, or if the controller can't represent the
+    /// filter (e.g. a range filter on a controller only supporting masks).
+    fn set_filter(&mut self, index: usize, filter: &FilterConfig) -> Result<(), Self::Error>;
+}
+
+fn is_extended(id: &Id) -> bool {
+    matches!(id, Id::Extended(_))
+}
+
+fn raw(id: &Id) -> u32 {
+    match id {
+        Id::Standard(id) => id.as_raw().into(),
+        Id::Extended(id) => id.as_raw(),
+    }
+}
+
+fn full_mask(id: &Id) -> u32 {
+    match id {
+        Id::Standard(_) => StandardId::MAX.as_raw().into(),
+        Id::Extended(_) => ExtendedId::MAX.as_raw(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn std_id(raw: u16) -> Id {
+        Id::Standard(StandardId::new(raw).unwrap())
+    }
+
+    fn ext_id(raw: u32) -> Id {
+        Id::Extended(ExtendedId::new(raw).unwrap())
+    }
+
+    #[test]
+    fn exact_match() {
+        let filter = FilterConfig::exact_match(std_id(0x123));
+        assert!(filter.matches(std_id(0x123)));
+        assert!(!filter.matches(std_id(0x122)));
+        assert!(!filter.matches(ext_id(0x123)));
+    }
+
+    #[test]
+    fn range() {
+        let filter = FilterConfig::range(ext_id(0x100), ext_id(0x1FF));
+        assert!(filter.matches(ext_id(0x100)));
+        assert!(filter.matches(ext_id(0x1FF)));
+        assert!(!filter.matches(ext_id(0x200)));
+        assert!(!filter.matches(std_id(0x150)));
+    }
+
+    #[test]
+    fn builder_mask() {
+        let filter = CanFilterBuilder::new()
+            .id(ext_id(0x1234_5678))
+            .mask_extended_id(0x1FFF_FF00)
+            .build();
+        assert!(filter.matches(ext_id(0x1234_56FF)));
+        assert!(!filter.matches(ext_id(0x1234_5778)));
+        assert!(FilterConfig::accept_all().matches(ext_id(0x1234_5778)));
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn builder_mask_mismatch() {
+        let _ = CanFilterBuilder::new()
+            .id(ext_id(0x123))
+            .mask_standard_id(0x7F0);
+    }
+}
@@ -104,6 +104,51 @@ pub enum Id {
     Extended(ExtendedId),
 }
 
+impl Id {
+    /// Returns `true` if `self` wins arbitration against `other`.
+    ///
+    /// This is equivalent to `self < other`, see the [`Ord`] implementation.
+    #[inline]
+    #[must_use]
+    pub fn beats(&self, other: &Id) -> bool {
+        self < other
+    }
+
+    /// Returns the arbitration field of a data frame with this identifier, in wire order.
+    ///
+    /// The bits are laid out as they are transmitted, most significant bit first:
+    ///
+    /// - bits 30..=20: base ID (ID-28 to ID-18),
+    /// - bit 19: SRR for extended frames (always recessive, `1`), RTR for standard frames (`0`),
+    /// - bit 18: IDE, `1` for extended frames,
+    /// - bits 17..=0: ID extension (ID-17 to ID-0), `0` for standard frames.
+    ///
+    /// Comparing the returned values is consistent with the [`Ord`] implementation of `Id`.
+    #[inline]
+    #[must_use]
+    pub fn arbitration_bits(&self) -> u32 {
+        match self {
+            Id::Standard(id) => u32::from(id.0) << 20,
+            Id::Extended(id) => {
+                (u32::from(id.standard_id().0) << 20) | (0b11 << 18) | (id.0 & ((1 << 18) - 1))
+            }
+        }
+    }
+
+    /// Returns the priority class of this identifier, from `0` (highest) to `7` (lowest).
+    ///
+    /// This is the 3 most significant bits of the base ID.
+    #[inline]
+    #[must_use]
+    pub fn standard_priority_class(&self) -> u8 {
+        let base = match self {
+            Id::Standard(id) => *id,
+            Id::Extended(id) => id.standard_id(),
+        };
+        (base.0 >> 8) as u8
+    }
+}
+
 /// Implement `Ord` according to the CAN arbitration rules
 ///
 /// When performing arbitration, frames are looked at bit for bit starting
@@ -216,4 +261,46 @@ mod tests {
         assert!(Id::Extended(ExtendedId((1 << 11) - 1)) < Id::Standard(StandardId(1)));
         assert!(Id::Standard(StandardId(1)) < Id::Extended(ExtendedId::MAX));
     }
+
+    #[test]
+    fn beats() {
+        let standard = Id::Standard(StandardId(0x100));
+        let extended = Id::Extended(ExtendedId(0x100 << 18));
+        assert!(standard.beats(&extended));
+        assert!(!extended.beats(&standard));
+        assert!(!standard.beats(&standard));
+    }
+
+    #[test]
+    fn arbitration_bits() {
+        assert_eq!(
+            Id::Standard(StandardId::MAX).arbitration_bits(),
+            0x7FF << 20
+        );
+        assert_eq!(
+            Id::Extended(ExtendedId::MAX).arbitration_bits(),
+            0x7FFF_FFFF
+        );
+
+        let ids = [
+            Id::Standard(StandardId::ZERO),
+            Id::Extended(ExtendedId::ZERO),
+            Id::Extended(ExtendedId((1 << 11) - 1)),
+            Id::Standard(StandardId(1)),
+            Id::Extended(ExtendedId::MAX),
+        ];
+        for a in ids {
+            for b in ids {
+                assert_eq!(a.cmp(&b), a.arbitration_bits().cmp(&b.arbitration_bits()));
+            }
+        }
+    }
+
+    #[test]
+    fn standard_priority_class() {
+        assert_eq!(Id::Standard(StandardId::ZERO).standard_priority_class(), 0);
+        assert_eq!(Id::Standard(StandardId::MAX).standard_priority_class(), 7);
+        assert_eq!(Id::Extended(ExtendedId::MAX).standard_priority_class(), 7);
+        assert_eq!(Id::Standard(StandardId(0x2FF)).standard_priority_class(), 2);
+    }
 }
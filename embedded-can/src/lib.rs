@@ -3,12 +3,25 @@
 #![warn(missing_docs)]
 #![no_std]
 
+pub mod asynch;
 pub mod blocking;
 pub mod nb;
+#[cfg(feature = "test-utils")]
+pub mod test_utils;
 
+mod fd;
+mod fifo;
+mod filter;
 mod id;
+mod state;
+mod timing;
 
+pub use fd::*;
+pub use fifo::*;
+pub use filter::*;
 pub use id::*;
+pub use state::*;
+pub use timing::*;
 
 /// A CAN2.0 Frame
 pub trait Frame: Sized {
@@ -49,6 +62,61 @@ pub trait Frame: Sized {
 
     /// Returns the frame data (0..8 bytes in length).
     fn data(&self) -> &[u8];
+
+    /// Creates a new remote frame requesting `dlc` bytes of data from the node sending `id`.
+    ///
+    /// This is equivalent to [`new_remote`](Frame::new_remote), and returns `None` if `dlc` is not
+    /// valid for a classic CAN remote frame, see [`dlc_for_rtr_request`].
+    fn new_rtr_request(id: impl Into<Id>, dlc: usize) -> Option<Self> {
+        Self::new_remote(id, dlc_for_rtr_request(dlc)?)
+    }
+
+    /// Returns the data length requested by this frame if it is a remote frame, `None` otherwise.
+    fn rtr_requested_dlc(&self) -> Option<usize> {
+        if self.is_remote_frame() {
+            Some(self.dlc())
+        } else {
+            None
+        }
+    }
+
+    /// Returns `true` if this frame is a valid response to the remote frame `request`.
+    ///
+    /// A response is a data frame with the same identifier as the request, carrying the number of
+    /// bytes requested.
+    fn is_rtr_response_to(&self, request: &Self) -> bool {
+        self.is_data_frame()
+            && self.id() == request.id()
+            && request.rtr_requested_dlc() == Some(self.data().len())
+    }
+
+    /// Converts a remote frame into a data frame with the same identifier, carrying the number of
+    /// bytes requested, all set to zero.
+    ///
+    /// This can be used as a template for the response to the request. Returns `None` if this
+    /// frame is not a remote frame, or if the data frame can't be created.
+    fn into_data_frame(self) -> Option<Self> {
+        let dlc = dlc_for_rtr_request(self.rtr_requested_dlc()?)?;
+        Self::new(self.id(), &[0; 8][..dlc])
+    }
+}
+
+/// Validates the data length code (DLC) of a classic CAN remote frame.
+///
+/// Returns `Some(requested_dlc)` if it is in the range 0..=8, `None` otherwise.
+///
+/// ```
+/// use embedded_can::dlc_for_rtr_request;
+///
+/// assert_eq!(dlc_for_rtr_request(8), Some(8));
+/// assert_eq!(dlc_for_rtr_request(9), None);
+/// ```
+pub const fn dlc_for_rtr_request(requested_dlc: usize) -> Option<usize> {
+    if requested_dlc <= 8 {
+        Some(requested_dlc)
+    } else {
+        None
+    }
 }
 
 /// CAN error
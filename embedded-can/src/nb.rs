@@ -26,3 +26,28 @@ pub trait Can {
     /// Returns a received frame if available.
     fn receive(&mut self) -> nb::Result<Self::Frame, Self::Error>;
 }
+
+/// A CAN interface receiving frames through a FIFO.
+///
+/// Controllers with several receive mailboxes don't necessarily deliver frames in the order they
+/// were received. Implementations of this trait must return frames in reception order.
+pub trait CanReceiveFifo {
+    /// Associated frame type.
+    type Frame: crate::Frame;
+
+    /// Associated error type.
+    type Error: crate::Error;
+
+    /// Returns a reference to the next frame in the FIFO, without removing it.
+    ///
+    /// Returns `Err(WouldBlock)` if the FIFO is empty.
+    fn peek_next(&mut self) -> nb::Result<&Self::Frame, Self::Error>;
+
+    /// Removes and returns the next frame in the FIFO.
+    ///
+    /// Returns `Err(WouldBlock)` if the FIFO is empty.
+    fn receive(&mut self) -> nb::Result<Self::Frame, Self::Error>;
+
+    /// Sets the behavior of the FIFO when a frame is received while it is full.
+    fn set_fifo_mode(&mut self, mode: crate::FifoMode) -> Result<(), Self::Error>;
+}
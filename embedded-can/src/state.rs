@@ -0,0 +1,62 @@
+//! CAN controller error state.
+
+/// Fault confinement state of a CAN controller.
+///
+/// CAN controllers keep track of transmit and receive errors with two counters (TEC and REC).
+/// The state is derived from these counters as described in ISO 11898-1.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub enum CanState {
+    /// Both error counters are below 128. The controller takes part in bus communication
+    /// normally and signals errors with active error flags.
+    ErrorActive,
+
+    /// At least one error counter is 128 or above. The controller still takes part in bus
+    /// communication, but only signals errors with passive error flags and has to wait
+    /// an additional suspend transmission time between transmissions.
+    ErrorPassive,
+
+    /// The transmit error counter exceeded 255. The controller does not take part in bus
+    /// communication until it recovers.
+    BusOff,
+}
+
+/// Query the fault confinement state of a CAN controller.
+///
+/// This allows applications to monitor the health of the bus.
+pub trait CanStatus {
+    /// Associated error type.
+    type Error: crate::Error;
+
+    /// Returns the current fault confinement state.
+    fn state(&mut self) -> Result<CanState, Self::Error>;
+
+    /// Returns the transmit error counter (TEC).
+    ///
+    /// Implementations should saturate at `255`, the controller is in the [`CanState::BusOff`]
+    /// state when the counter exceeds this value.
+    fn tx_error_count(&mut self) -> Result<u8, Self::Error>;
+
+    /// Returns the receive error counter (REC).
+    ///
+    /// Implementations should saturate at `255`.
+    fn rx_error_count(&mut self) -> Result<u8, Self::Error>;
+}
+
+/// Recover a CAN controller from the bus-off state.
+///
+/// Some controllers recover automatically after 128 occurrences of 11 consecutive recessive bits,
+/// while others require an explicit recovery command.
+pub trait CanRecover {
+    /// Associated error type.
+    type Error: crate::Error;
+
+    /// Requests recovery from the [`CanState::BusOff`] state.
+    ///
+    /// This returns once the recovery has been initiated. The controller only leaves the bus-off
+    /// state after the recovery sequence mandated by ISO 11898-1 has been observed on the bus,
+    /// which can be checked with [`CanStatus::state`].
+    ///
+    /// Calling this method while the controller is not in the bus-off state has no effect.
+    fn recover_from_bus_off(&mut self) -> Result<(), Self::Error>;
+}
@@ -0,0 +1,258 @@
+//! Utilities for testing CAN drivers without hardware.
+
+use crate::nb::Can;
+use crate::{ErrorKind, Frame};
+
+/// Number of frames [`LoopbackCan`] can hold, in each of its queues.
+pub const LOOPBACK_CAPACITY: usize = 8;
+
+/// CAN interface receiving the frames it transmits, as a controller in loopback mode.
+///
+/// Every frame transmitted is queued to be returned by [`receive`](Can::receive), and recorded to
+/// be inspected with [`flush_all`](LoopbackCan::flush_all). This allows testing drivers generic
+/// over [`nb::Can`](crate::nb::Can) on the host.
+///
+/// [`transmit`](Can::transmit) returns `WouldBlock` if either queue is full, and
+/// [`receive`](Can::receive) returns `WouldBlock` if no frame is queued. Errors can be simulated
+/// with [`CanErrorInjection`].
+#[derive(Debug)]
+pub struct LoopbackCan<F: Frame> {
+    received: heapless::Deque<F, LOOPBACK_CAPACITY>,
+    sent: heapless::Vec<F, LOOPBACK_CAPACITY>,
+    injected: Option<ErrorKind>,
+    counting: bool,
+    stats: CanErrorStats,
+}
+
+impl<F: Frame> LoopbackCan<F> {
+    /// Creates a new loopback interface, with no frame queued.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            received: heapless::Deque::new(),
+            sent: heapless::Vec::new(),
+            injected: None,
+            counting: false,
+            stats: CanErrorStats {
+                tx_errors: 0,
+                rx_errors: 0,
+                bus_off_count: 0,
+            },
+        }
+    }
+
+    /// Returns the frames transmitted since the last call, and forgets them.
+    ///
+    /// Frames transmitted are still returned by [`receive`](Can::receive).
+    pub fn flush_all(&mut self) -> heapless::Vec<F, LOOPBACK_CAPACITY> {
+        core::mem::take(&mut self.sent)
+    }
+}
+
+impl<F: Frame + Clone> LoopbackCan<F> {
+    /// Creates a new loopback interface with `frames` queued to be received, for example to inject
+    /// the responses of other nodes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if more than [`LOOPBACK_CAPACITY`] frames are given.
+    pub fn with_preloaded_frames(frames: &[F]) -> Self {
+        assert!(
+            frames.len() <= LOOPBACK_CAPACITY,
+            "too many preloaded frames"
+        );
+        let mut can = Self::new();
+        for frame in frames {
+            let _ = can.received.push_back(frame.clone());
+        }
+        can
+    }
+}
+
+impl<F: Frame> Default for LoopbackCan<F> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<F: Frame + Clone> Can for LoopbackCan<F> {
+    type Frame = F;
+    type Error = ErrorKind;
+
+    fn transmit(&mut self, frame: &F) -> nb::Result<Option<F>, Self::Error> {
+        if let Some(kind) = self.injected.take() {
+            if self.counting {
+                self.stats.tx_errors = self.stats.tx_errors.saturating_add(1);
+            }
+            return Err(nb::Error::Other(kind));
+        }
+        if self.received.is_full() || self.sent.is_full() {
+            return Err(nb::Error::WouldBlock);
+        }
+        let _ = self.received.push_back(frame.clone());
+        let _ = self.sent.push(frame.clone());
+        Ok(None)
+    }
+
+    fn receive(&mut self) -> nb::Result<F, Self::Error> {
+        if let Some(kind) = self.injected.take() {
+            if self.counting {
+                self.stats.rx_errors = self.stats.rx_errors.saturating_add(1);
+            }
+            return Err(nb::Error::Other(kind));
+        }
+        self.received.pop_front().ok_or(nb::Error::WouldBlock)
+    }
+}
+
+/// Error statistics of a CAN interface, returned by [`CanErrorInjection::error_stats`].
+///
+/// Unlike the error counters of [`CanStatus`](crate::CanStatus), these count errors, and are
+/// not decremented by successful transfers. They saturate at `255`.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub struct CanErrorStats {
+    /// Number of failed transmissions.
+    pub tx_errors: u8,
+    /// Number of failed receptions.
+    pub rx_errors: u8,
+    /// Number of times the interface entered the [`BusOff`](crate::CanState::BusOff) state.
+    pub bus_off_count: u8,
+}
+
+/// Simulate errors in a CAN interface, to test the error handling of drivers.
+///
+/// This is meant to be implemented by test doubles, such as [`LoopbackCan`], and by HALs of
+/// controllers able to generate errors in hardware for testing.
+pub trait CanErrorInjection {
+    /// Associated error type.
+    type Error: crate::Error;
+
+    /// Makes the next transmission or reception fail with an error of kind `kind`.
+    ///
+    /// Injecting an error while one is already pending replaces it.
+    fn inject_error(&mut self, kind: ErrorKind) -> Result<(), Self::Error>;
+
+    /// Starts counting errors in the statistics returned by
+    /// [`error_stats`](CanErrorInjection::error_stats).
+    fn enable_error_counting(&mut self) -> Result<(), Self::Error>;
+
+    /// Returns the errors counted since error counting was enabled.
+    fn error_stats(&mut self) -> Result<CanErrorStats, Self::Error>;
+}
+
+/// [`LoopbackCan`] never goes bus-off, so `bus_off_count` is always `0`.
+impl<F: Frame> CanErrorInjection for LoopbackCan<F> {
+    type Error = ErrorKind;
+
+    fn inject_error(&mut self, kind: ErrorKind) -> Result<(), Self::Error> {
+        self.injected = Some(kind);
+        Ok(())
+    }
+
+    fn enable_error_counting(&mut self) -> Result<(), Self::Error> {
+        self.counting = true;
+        Ok(())
+    }
+
+    fn error_stats(&mut self) -> Result<CanErrorStats, Self::Error> {
+        Ok(self.stats)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Id, StandardId};
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct TestFrame {
+        id: Id,
+        data: heapless::Vec<u8, 8>,
+    }
+
+    impl Frame for TestFrame {
+        fn new(id: impl Into<Id>, data: &[u8]) -> Option<Self> {
+            Some(Self {
+                id: id.into(),
+                data: heapless::Vec::from_slice(data).ok()?,
+            })
+        }
+
+        fn new_remote(_id: impl Into<Id>, _dlc: usize) -> Option<Self> {
+            None
+        }
+
+        fn is_extended(&self) -> bool {
+            matches!(self.id, Id::Extended(_))
+        }
+
+        fn is_remote_frame(&self) -> bool {
+            false
+        }
+
+        fn id(&self) -> Id {
+            self.id
+        }
+
+        fn dlc(&self) -> usize {
+            self.data.len()
+        }
+
+        fn data(&self) -> &[u8] {
+            &self.data
+        }
+    }
+
+    fn frame(id: u16, data: &[u8]) -> TestFrame {
+        TestFrame::new(StandardId::new(id).unwrap(), data).unwrap()
+    }
+
+    #[test]
+    fn loopback() {
+        let mut can = LoopbackCan::with_preloaded_frames(&[frame(0x100, &[1])]);
+        can.transmit(&frame(0x200, &[2, 3])).unwrap();
+
+        assert_eq!(can.receive(), Ok(frame(0x100, &[1])));
+        assert_eq!(can.receive(), Ok(frame(0x200, &[2, 3])));
+        assert_eq!(can.receive(), Err(nb::Error::WouldBlock));
+        assert_eq!(can.flush_all().as_slice(), &[frame(0x200, &[2, 3])]);
+        assert!(can.flush_all().is_empty());
+    }
+
+    #[test]
+    fn error_injection() {
+        let mut can = LoopbackCan::new();
+        can.inject_error(ErrorKind::Bit).unwrap();
+        assert_eq!(
+            can.transmit(&frame(0x100, &[])),
+            Err(nb::Error::Other(ErrorKind::Bit))
+        );
+        assert_eq!(can.error_stats().unwrap(), CanErrorStats::default());
+
+        can.enable_error_counting().unwrap();
+        can.inject_error(ErrorKind::Acknowledge).unwrap();
+        can.transmit(&frame(0x100, &[])).unwrap_err();
+        can.inject_error(ErrorKind::Crc).unwrap();
+        assert_eq!(can.receive(), Err(nb::Error::Other(ErrorKind::Crc)));
+        assert_eq!(
+            can.error_stats().unwrap(),
+            CanErrorStats {
+                tx_errors: 1,
+                rx_errors: 1,
+                bus_off_count: 0,
+            }
+        );
+        assert_eq!(can.receive(), Err(nb::Error::WouldBlock));
+    }
+
+    #[test]
+    fn full() {
+        let mut can = LoopbackCan::new();
+        for i in 0..LOOPBACK_CAPACITY {
+            can.transmit(&frame(i as u16, &[])).unwrap();
+        }
+        assert_eq!(can.transmit(&frame(0x7FF, &[])), Err(nb::Error::WouldBlock));
+    }
+}
@@ -0,0 +1,173 @@
+//! CAN bit timing configuration.
+
+/// CAN bit timing register values.
+///
+/// The bit time is divided in time quanta (tq) of `prescaler` controller clock cycles. A bit
+/// consists of the synchronization segment (always 1 tq), followed by `time_segment_1` and
+/// `time_segment_2`. The bus is sampled between the two time segments.
+///
+/// ``` text
+/// |<------------------------------- bit time ---------------------------------->|
+/// | sync (1 tq) | time_segment_1 (prop + phase 1) | time_segment_2 (phase 2) |
+///                                                 ^ sample point
+/// ```
+///
+/// The values are the actual numbers of time quanta and clock cycles, not the register values,
+/// which are often encoded minus one.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub struct CanBitTiming {
+    /// Number of controller clock cycles per time quantum.
+    pub prescaler: u16,
+    /// Time segment 1 (propagation and phase 1 segments), in time quanta.
+    pub time_segment_1: u8,
+    /// Time segment 2 (phase 2 segment), in time quanta.
+    pub time_segment_2: u8,
+    /// Synchronization jump width, in time quanta.
+    pub sync_jump_width: u8,
+}
+
+impl CanBitTiming {
+    /// Returns the number of time quanta per bit.
+    #[inline]
+    #[must_use]
+    pub const fn time_quanta(&self) -> u32 {
+        1 + self.time_segment_1 as u32 + self.time_segment_2 as u32
+    }
+
+    /// Returns the bit rate achieved with a controller clock of `clock_hz`, in bits per second.
+    ///
+    /// Returns `None` if `prescaler` is zero.
+    #[inline]
+    #[must_use]
+    pub const fn bitrate(&self, clock_hz: u32) -> Option<u32> {
+        clock_hz.checked_div(self.prescaler as u32 * self.time_quanta())
+    }
+}
+
+/// Configure the bit timing of a CAN controller.
+pub trait ConfigureCanBitTiming {
+    /// Associated error type.
+    type Error: crate::Error;
+
+    /// Sets the bit timing.
+    ///
+    /// Returns an error if the controller can't represent the timing, e.g. a time segment longer
+    /// than its register allows.
+    fn set_bit_timing(&mut self, timing: &CanBitTiming) -> Result<(), Self::Error>;
+}
+
+/// Computes a bit timing achieving exactly `target_baud` with a controller clock of `clock_hz`.
+///
+/// This searches the parameter space supported by most CAN controllers:
+///
+/// - `prescaler` in the range 1..=1024,
+/// - 8 to 25 time quanta per bit,
+/// - `time_segment_1` in the range 1..=16 and `time_segment_2` in the range 1..=8.
+///
+/// The sample point closest to 87.5% is picked, as recommended by CiA 301, preferring more time
+/// quanta per bit on a tie. The synchronization jump width is `time_segment_2`, up to 4.
+///
+/// Returns `None` if no combination achieves exactly `target_baud`.
+///
+/// ```
+/// use embedded_can::compute_bit_timing;
+///
+/// let timing = compute_bit_timing(48_000_000, 500_000).unwrap();
+/// assert_eq!(timing.bitrate(48_000_000), Some(500_000));
+/// assert_eq!(timing.time_quanta(), 16);
+/// assert_eq!(timing.time_segment_2, 2);
+///
+/// assert_eq!(compute_bit_timing(8_000_000, 1_000_001), None);
+/// ```
+#[must_use]
+pub fn compute_bit_timing(clock_hz: u32, target_baud: u32) -> Option<CanBitTiming> {
+    if target_baud == 0 {
+        return None;
+    }
+
+    let mut best: Option<(u32, CanBitTiming)> = None;
+    for prescaler in 1..=1024u16 {
+        let cycles_per_bit = u64::from(prescaler) * u64::from(target_baud);
+        if u64::from(clock_hz) % cycles_per_bit != 0 {
+            continue;
+        }
+        let tq = u64::from(clock_hz) / cycles_per_bit;
+        if !(8..=25).contains(&tq) {
+            continue;
+        }
+        let tq = tq as u32;
+
+        // Sample point after `1 + time_segment_1` time quanta, rounded to the nearest one.
+        let sample = ((875 * tq + 500) / 1000).clamp(tq - 8, 17);
+        let time_segment_1 = sample - 1;
+        let time_segment_2 = tq - sample;
+        let error = (sample * 1000).abs_diff(875 * tq) * 1000 / tq;
+
+        if best.map_or(true, |(best_error, _)| error < best_error) {
+            best = Some((
+                error,
+                CanBitTiming {
+                    prescaler,
+                    time_segment_1: time_segment_1 as u8,
+                    time_segment_2: time_segment_2 as u8,
+                    sync_jump_width: time_segment_2.min(4) as u8,
+                },
+            ));
+        }
+    }
+    best.map(|(_, timing)| timing)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn common_bitrates() {
+        for clock_hz in [8_000_000, 16_000_000, 36_000_000, 42_000_000, 80_000_000] {
+            for baud in [125_000, 250_000, 500_000, 1_000_000] {
+                let timing =
+                    compute_bit_timing(clock_hz, baud).expect("bitrate should be achievable");
+                assert_eq!(timing.bitrate(clock_hz), Some(baud));
+                assert!((1..=16).contains(&timing.time_segment_1));
+                assert!((1..=8).contains(&timing.time_segment_2));
+                assert!((8..=25).contains(&timing.time_quanta()));
+                assert!(timing.sync_jump_width <= timing.time_segment_2);
+            }
+        }
+    }
+
+    #[test]
+    fn sample_point() {
+        let timing = compute_bit_timing(8_000_000, 500_000).unwrap();
+        assert_eq!(
+            timing,
+            CanBitTiming {
+                prescaler: 1,
+                time_segment_1: 13,
+                time_segment_2: 2,
+                sync_jump_width: 2,
+            }
+        );
+    }
+
+    #[test]
+    fn zero_prescaler() {
+        let timing = CanBitTiming {
+            prescaler: 0,
+            time_segment_1: 13,
+            time_segment_2: 2,
+            sync_jump_width: 2,
+        };
+        assert_eq!(timing.bitrate(8_000_000), None);
+    }
+
+    #[test]
+    fn impossible() {
+        assert_eq!(compute_bit_timing(8_000_000, 0), None);
+        assert_eq!(compute_bit_timing(1_000_000, 1_000_000), None);
+        // 8 MHz / 3 Mbit/s isn't an integer number of clock cycles per bit.
+        assert_eq!(compute_bit_timing(8_000_000, 3_000_000), None);
+    }
+}
@@ -0,0 +1,100 @@
+//! Asynchronous analog-digital conversion traits.
+
+pub use embedded_hal::adc::{Error, ErrorKind, ErrorType};
+
+/// Asynchronous ADC with an analog watchdog.
+///
+/// This is the asynchronous version of [`embedded_hal::adc::AdcWatchdog`], with an additional
+/// [`wait_for_watchdog_trigger`](AdcWatchdog::wait_for_watchdog_trigger) method to wait for the
+/// watchdog without polling.
+pub trait AdcWatchdog: ErrorType {
+    /// Set the watchdog thresholds, in nanovolts.
+    ///
+    /// Implementations round the thresholds to the ADC resolution. Returns an error of kind
+    /// [`ErrorKind::InvalidInput`] if `low_nv` is greater than `high_nv`.
+    async fn set_watchdog_thresholds(
+        &mut self,
+        low_nv: i64,
+        high_nv: i64,
+    ) -> Result<(), Self::Error>;
+
+    /// Enable the watchdog.
+    async fn enable_watchdog(&mut self) -> Result<(), Self::Error>;
+
+    /// Disable the watchdog.
+    async fn disable_watchdog(&mut self) -> Result<(), Self::Error>;
+
+    /// Returns `true` if a conversion result was outside of the thresholds since the last call.
+    ///
+    /// Implementations clear the watchdog flag when this returns `true`.
+    async fn is_watchdog_triggered(&mut self) -> Result<bool, Self::Error>;
+
+    /// Wait until a conversion result is outside of the thresholds.
+    ///
+    /// If the watchdog was triggered since the flag was last cleared, return immediately.
+    /// Implementations clear the watchdog flag before returning.
+    async fn wait_for_watchdog_trigger(&mut self) -> Result<(), Self::Error>;
+}
+
+impl<T: AdcWatchdog + ?Sized> AdcWatchdog for &mut T {
+    #[inline]
+    async fn set_watchdog_thresholds(
+        &mut self,
+        low_nv: i64,
+        high_nv: i64,
+    ) -> Result<(), Self::Error> {
+        T::set_watchdog_thresholds(self, low_nv, high_nv).await
+    }
+
+    #[inline]
+    async fn enable_watchdog(&mut self) -> Result<(), Self::Error> {
+        T::enable_watchdog(self).await
+    }
+
+    #[inline]
+    async fn disable_watchdog(&mut self) -> Result<(), Self::Error> {
+        T::disable_watchdog(self).await
+    }
+
+    #[inline]
+    async fn is_watchdog_triggered(&mut self) -> Result<bool, Self::Error> {
+        T::is_watchdog_triggered(self).await
+    }
+
+    #[inline]
+    async fn wait_for_watchdog_trigger(&mut self) -> Result<(), Self::Error> {
+        T::wait_for_watchdog_trigger(self).await
+    }
+}
+
+/// Asynchronous ADC scanning several channels in a single conversion sequence.
+///
+/// This is the asynchronous version of [`embedded_hal::adc::AdcScan`]. It allows DMA-driven
+/// scans to release the CPU during the conversions.
+pub trait AdcScan: ErrorType {
+    /// Scan `channels` in order, storing the result of each channel in nV (nanovolts) in the
+    /// matching element of `results`.
+    ///
+    /// Channel indices are HAL-defined. Returns an error of kind [`ErrorKind::InvalidInput`] if
+    /// a channel doesn't exist, or if the hardware can't scan the given sequence.
+    ///
+    /// # Panics
+    ///
+    /// Implementations panic if `channels` and `results` have different lengths.
+    async fn scan_channels(
+        &mut self,
+        channels: &[u8],
+        results: &mut [i64],
+    ) -> Result<(), Self::Error>;
+}
+
+impl<T: AdcScan + ?Sized> AdcScan for &mut T {
+    #[inline]
+    async fn scan_channels(
+        &mut self,
+        channels: &[u8],
+        results: &mut [i64],
+    ) -> Result<(), Self::Error> {
+        T::scan_channels(self, channels, results).await
+    }
+}
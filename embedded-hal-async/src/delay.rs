@@ -1,5 +1,7 @@
 //! Delays.
 
+use core::time::Duration;
+
 /// Delay with up to nanosecond precision.
 pub trait DelayNs {
     /// Pauses execution for at minimum `ns` nanoseconds. Pause can be longer
@@ -26,6 +28,27 @@ pub trait DelayNs {
         }
         self.delay_ns(ms * 1_000_000).await;
     }
+
+    /// Pauses execution for at minimum `duration`. Pause can be longer
+    /// if the implementation requires it due to precision/timing issues.
+    ///
+    /// Durations longer than `u32::MAX` nanoseconds (~4.3 seconds) are split in several calls to `delay_ns`.
+    #[inline]
+    async fn delay_duration(&mut self, duration: Duration) {
+        let mut ns = duration.as_nanos();
+
+        // Avoid overflow if the duration does not fit in a single `delay_ns` call
+        while ns > u128::from(u32::MAX) {
+            ns -= u128::from(u32::MAX);
+            self.delay_ns(u32::MAX).await;
+        }
+
+        // This is safe because we know that `ns <= u32::MAX`
+        #[allow(clippy::cast_possible_truncation)]
+        {
+            self.delay_ns(ns as u32).await;
+        }
+    }
 }
 
 impl<T> DelayNs for &mut T
@@ -46,4 +69,9 @@ where
     async fn delay_ms(&mut self, ms: u32) {
         T::delay_ms(self, ms).await;
     }
+
+    #[inline]
+    async fn delay_duration(&mut self, duration: Duration) {
+        T::delay_duration(self, duration).await;
+    }
 }
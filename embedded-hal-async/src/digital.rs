@@ -15,7 +15,10 @@
 //!         .expect("failed to await input pin")
 //! }
 //! ```
-pub use embedded_hal::digital::{Error, ErrorKind, ErrorType};
+pub use embedded_hal::digital::{DriveStrength, Error, ErrorKind, ErrorType};
+
+use crate::delay::DelayNs;
+use embedded_hal::digital::InputPin;
 
 /// Asynchronously wait for GPIO pin state.
 pub trait Wait: ErrorType {
@@ -75,3 +78,217 @@ impl<T: Wait + ?Sized> Wait for &mut T {
         T::wait_for_any_edge(self).await
     }
 }
+
+/// Async version of [`DebouncedPin`](embedded_hal::digital::DebouncedPin), waiting between samples
+/// with an async delay.
+///
+/// Each read samples the pin repeatedly, waiting `delay_us` microseconds between samples, until
+/// `samples` consecutive samples agree. Since there is no async `InputPin` trait, the debounced
+/// level is read with the inherent [`is_high`](AsyncDebouncedPin::is_high) and
+/// [`is_low`](AsyncDebouncedPin::is_low) methods.
+#[derive(Debug)]
+pub struct AsyncDebouncedPin<P, D> {
+    pin: P,
+    delay: D,
+    delay_us: u32,
+    samples: u8,
+}
+
+impl<P, D> AsyncDebouncedPin<P, D> {
+    /// Create a new `AsyncDebouncedPin` requiring `samples` consecutive equal samples,
+    /// `delay_us` microseconds apart.
+    ///
+    /// # Panics
+    ///
+    /// Panics in debug builds if `samples` is zero. In release builds, zero is treated as one,
+    /// i.e. no debouncing.
+    #[inline]
+    pub fn new(pin: P, delay: D, delay_us: u32, samples: u8) -> Self {
+        debug_assert!(samples > 0, "samples must not be zero");
+        Self {
+            pin,
+            delay,
+            delay_us,
+            samples: samples.max(1),
+        }
+    }
+
+    /// Consumes the `AsyncDebouncedPin`, returning the pin and delay.
+    #[inline]
+    pub fn into_inner(self) -> (P, D) {
+        (self.pin, self.delay)
+    }
+}
+
+impl<P: InputPin, D: DelayNs> AsyncDebouncedPin<P, D> {
+    /// Is the debounced input pin high?
+    pub async fn is_high(&mut self) -> Result<bool, P::Error> {
+        let mut level = self.pin.is_high()?;
+        let mut stable = 1;
+        while stable < self.samples {
+            self.delay.delay_us(self.delay_us).await;
+            let sample = self.pin.is_high()?;
+            if sample == level {
+                stable += 1;
+            } else {
+                level = sample;
+                stable = 1;
+            }
+        }
+        Ok(level)
+    }
+
+    /// Is the debounced input pin low?
+    #[inline]
+    pub async fn is_low(&mut self) -> Result<bool, P::Error> {
+        self.is_high().await.map(|high| !high)
+    }
+}
+
+impl<P: ErrorType, D> ErrorType for AsyncDebouncedPin<P, D> {
+    type Error = P::Error;
+}
+
+/// Asynchronous configuration of the electrical characteristics of an output pin.
+///
+/// This is the asynchronous version of [`embedded_hal::digital::ConfigurePin`], intended for
+/// pins whose configuration requires bus communication, such as GPIO expanders over I2C or SPI.
+///
+/// *NOTE* these settings only affect the electrical characteristics of the pin, not its logical state.
+pub trait ConfigurePin: ErrorType {
+    /// Set the output drive strength of the pin.
+    async fn set_drive_strength(&mut self, strength: DriveStrength) -> Result<(), Self::Error>;
+
+    /// Set the slew rate of the pin to fast (`true`) or slow (`false`).
+    async fn set_slew_rate(&mut self, fast: bool) -> Result<(), Self::Error>;
+}
+
+impl<T: ConfigurePin + ?Sized> ConfigurePin for &mut T {
+    #[inline]
+    async fn set_drive_strength(&mut self, strength: DriveStrength) -> Result<(), Self::Error> {
+        T::set_drive_strength(self, strength).await
+    }
+
+    #[inline]
+    async fn set_slew_rate(&mut self, fast: bool) -> Result<(), Self::Error> {
+        T::set_slew_rate(self, fast).await
+    }
+}
+
+/// Asynchronous group of pins sharing an interrupt.
+///
+/// This is the asynchronous version of [`embedded_hal::digital::InterruptGroup`], with an
+/// additional [`wait_any`](InterruptGroup::wait_any) method to wait for an interrupt without
+/// polling.
+pub trait InterruptGroup: ErrorType {
+    /// Returns a bitmask of the pins with a pending interrupt.
+    async fn pending_pins(&mut self) -> Result<u32, Self::Error>;
+
+    /// Clear the pending interrupts of the pins in `mask`.
+    async fn clear_pending(&mut self, mask: u32) -> Result<(), Self::Error>;
+
+    /// Wait until any pin of the group has a pending interrupt.
+    ///
+    /// Returns the bitmask of the pins with a pending interrupt, and clears them. If interrupts
+    /// are already pending, return immediately.
+    async fn wait_any(&mut self) -> Result<u32, Self::Error>;
+}
+
+impl<T: InterruptGroup + ?Sized> InterruptGroup for &mut T {
+    #[inline]
+    async fn pending_pins(&mut self) -> Result<u32, Self::Error> {
+        T::pending_pins(self).await
+    }
+
+    #[inline]
+    async fn clear_pending(&mut self, mask: u32) -> Result<(), Self::Error> {
+        T::clear_pending(self, mask).await
+    }
+
+    #[inline]
+    async fn wait_any(&mut self) -> Result<u32, Self::Error> {
+        T::wait_any(self).await
+    }
+}
+
+/// Wrapper counting how many times a [`Wait`] pin has triggered.
+///
+/// Each `wait_for_*` call that completes successfully increments the counter. This is useful for
+/// profiling interrupt load, or for asserting how many transitions occurred in tests.
+///
+/// Calls that return an error, or whose future is dropped before completing, are not counted.
+#[derive(Debug)]
+pub struct InterruptCounter<P> {
+    pin: P,
+    count: u64,
+}
+
+impl<P: Wait> InterruptCounter<P> {
+    /// Create a new counter wrapping `pin`, starting at zero.
+    #[inline]
+    pub fn new(pin: P) -> Self {
+        Self { pin, count: 0 }
+    }
+
+    /// Returns the number of completed waits since creation or the last [`reset_count`](Self::reset_count).
+    #[inline]
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Resets the counter to zero.
+    #[inline]
+    pub fn reset_count(&mut self) {
+        self.count = 0;
+    }
+
+    /// Consumes the counter, returning the wrapped pin.
+    #[inline]
+    pub fn into_inner(self) -> P {
+        self.pin
+    }
+
+    #[inline]
+    fn counted(&mut self, res: Result<(), P::Error>) -> Result<(), P::Error> {
+        if res.is_ok() {
+            self.count = self.count.wrapping_add(1);
+        }
+        res
+    }
+}
+
+impl<P: Wait> ErrorType for InterruptCounter<P> {
+    type Error = P::Error;
+}
+
+impl<P: Wait> Wait for InterruptCounter<P> {
+    #[inline]
+    async fn wait_for_high(&mut self) -> Result<(), Self::Error> {
+        let res = self.pin.wait_for_high().await;
+        self.counted(res)
+    }
+
+    #[inline]
+    async fn wait_for_low(&mut self) -> Result<(), Self::Error> {
+        let res = self.pin.wait_for_low().await;
+        self.counted(res)
+    }
+
+    #[inline]
+    async fn wait_for_rising_edge(&mut self) -> Result<(), Self::Error> {
+        let res = self.pin.wait_for_rising_edge().await;
+        self.counted(res)
+    }
+
+    #[inline]
+    async fn wait_for_falling_edge(&mut self) -> Result<(), Self::Error> {
+        let res = self.pin.wait_for_falling_edge().await;
+        self.counted(res)
+    }
+
+    #[inline]
+    async fn wait_for_any_edge(&mut self) -> Result<(), Self::Error> {
+        let res = self.pin.wait_for_any_edge().await;
+        self.counted(res)
+    }
+}
@@ -110,7 +110,9 @@ pub trait I2c<A: AddressMode = SevenBitAddress>: ErrorType {
     /// Transaction contract:
     /// - Before executing the first operation an ST is sent automatically. This is followed by SAD+R/W as appropriate.
     /// - Data from adjacent operations of the same type are sent after each other without an SP or SR.
-    /// - Between adjacent operations of a different type an SR and SAD+R/W is sent.
+    /// - Between adjacent operations of a different type an SR and SAD+R/W is sent implicitly, to
+    ///   the same address.
+    /// - No SP is ever sent between operations: the whole transaction is atomic on the bus.
     /// - After executing the last operation an SP is sent automatically.
     /// - At the end of each read operation (before SP or SR), the master does not send an acknowledge for the last byte.
     ///
@@ -155,3 +157,23 @@ impl<A: AddressMode, T: I2c<A> + ?Sized> I2c<A> for &mut T {
         T::transaction(self, address, operations).await
     }
 }
+
+/// Wait for SMBus Host Notify messages.
+///
+/// This is the asynchronous companion of [`embedded_hal::i2c::SmBusHost`]: instead of polling for
+/// the last Host Notify message, the future completes once a device sends one. This requires the
+/// controller to receive messages at the SMBus Host address (`0x08`), which is implemented by the
+/// HAL.
+pub trait SmBusHostNotify: ErrorType {
+    /// Wait for a Host Notify message.
+    ///
+    /// Returns the 7-bit address of the notifying device, and its status word.
+    async fn wait_for_host_notify(&mut self) -> Result<(SevenBitAddress, u16), Self::Error>;
+}
+
+impl<T: SmBusHostNotify + ?Sized> SmBusHostNotify for &mut T {
+    #[inline]
+    async fn wait_for_host_notify(&mut self) -> Result<(SevenBitAddress, u16), Self::Error> {
+        T::wait_for_host_notify(self).await
+    }
+}
@@ -0,0 +1,115 @@
+//! Asynchronous Pulse Width Modulation (PWM) traits.
+
+pub use embedded_hal::pwm::{CaptureEdge, Error, ErrorKind, ErrorType, PwmCountMode};
+
+/// Asynchronous PWM peripheral with a configurable counter mode.
+///
+/// This is the asynchronous version of [`embedded_hal::pwm::SetPwmMode`], intended for
+/// PWM controllers whose configuration requires bus communication, such as external
+/// PWM drivers over I2C or SPI.
+pub trait SetPwmMode: ErrorType {
+    /// Set the counter mode.
+    ///
+    /// For the same timer period, the frequency seen at the output in [`PwmCountMode::CenterAligned`]
+    /// mode is half the frequency in [`PwmCountMode::EdgeAligned`] mode, since the counter
+    /// has to count both up and down.
+    ///
+    /// Switching modes while the output is active may briefly glitch the output.
+    async fn set_count_mode(&mut self, mode: PwmCountMode) -> Result<(), Self::Error>;
+}
+
+impl<T: SetPwmMode + ?Sized> SetPwmMode for &mut T {
+    #[inline]
+    async fn set_count_mode(&mut self, mode: PwmCountMode) -> Result<(), Self::Error> {
+        T::set_count_mode(self, mode).await
+    }
+}
+
+/// Asynchronous PWM peripheral with a hardware fault input.
+///
+/// This is the asynchronous version of [`embedded_hal::pwm::PwmFaultProtection`], with an
+/// additional [`wait_for_fault`](PwmFaultProtection::wait_for_fault) method to wait for a fault
+/// without polling.
+pub trait PwmFaultProtection: ErrorType {
+    /// Enable the fault input, with the given polarity.
+    ///
+    /// If `active_high` is `true`, a fault is signaled when the input is high, otherwise when it's low.
+    async fn enable_fault_input(&mut self, active_high: bool) -> Result<(), Self::Error>;
+
+    /// Disable the fault input.
+    async fn disable_fault_input(&mut self) -> Result<(), Self::Error>;
+
+    /// Returns `true` if a fault has disabled the outputs and hasn't been cleared yet.
+    async fn is_fault_active(&mut self) -> Result<bool, Self::Error>;
+
+    /// Clear the fault, re-enabling the outputs.
+    ///
+    /// If the fault input is still asserted, the fault is immediately signaled again.
+    async fn clear_fault(&mut self) -> Result<(), Self::Error>;
+
+    /// Wait until a fault disables the outputs. If a fault is already active, return immediately.
+    async fn wait_for_fault(&mut self) -> Result<(), Self::Error>;
+}
+
+impl<T: PwmFaultProtection + ?Sized> PwmFaultProtection for &mut T {
+    #[inline]
+    async fn enable_fault_input(&mut self, active_high: bool) -> Result<(), Self::Error> {
+        T::enable_fault_input(self, active_high).await
+    }
+
+    #[inline]
+    async fn disable_fault_input(&mut self) -> Result<(), Self::Error> {
+        T::disable_fault_input(self).await
+    }
+
+    #[inline]
+    async fn is_fault_active(&mut self) -> Result<bool, Self::Error> {
+        T::is_fault_active(self).await
+    }
+
+    #[inline]
+    async fn clear_fault(&mut self) -> Result<(), Self::Error> {
+        T::clear_fault(self).await
+    }
+
+    #[inline]
+    async fn wait_for_fault(&mut self) -> Result<(), Self::Error> {
+        T::wait_for_fault(self).await
+    }
+}
+
+/// Asynchronous timer channel measuring an incoming signal (input capture).
+///
+/// This is the asynchronous version of [`embedded_hal::pwm::InputCapture`]: measurements yield
+/// while waiting for the capture edges, instead of blocking.
+pub trait InputCapture: ErrorType {
+    /// Set the edge captured by [`measure_period_ns`](InputCapture::measure_period_ns).
+    async fn set_capture_edge(&mut self, edge: CaptureEdge) -> Result<(), Self::Error>;
+
+    /// Measure the period of the input signal, in nanoseconds.
+    ///
+    /// This waits for two consecutive capture edges, and returns the time between them.
+    async fn measure_period_ns(&mut self) -> Result<u32, Self::Error>;
+
+    /// Measure the pulse width (high time) of the input signal, in nanoseconds.
+    ///
+    /// This waits for a rising edge followed by a falling edge, and returns the time between them.
+    async fn measure_pulse_width_ns(&mut self) -> Result<u32, Self::Error>;
+}
+
+impl<T: InputCapture + ?Sized> InputCapture for &mut T {
+    #[inline]
+    async fn set_capture_edge(&mut self, edge: CaptureEdge) -> Result<(), Self::Error> {
+        T::set_capture_edge(self, edge).await
+    }
+
+    #[inline]
+    async fn measure_period_ns(&mut self) -> Result<u32, Self::Error> {
+        T::measure_period_ns(self).await
+    }
+
+    #[inline]
+    async fn measure_pulse_width_ns(&mut self) -> Result<u32, Self::Error> {
+        T::measure_pulse_width_ns(self).await
+    }
+}
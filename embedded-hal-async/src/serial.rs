@@ -0,0 +1,191 @@
+//! Asynchronous serial interface.
+
+#[cfg(feature = "defmt-03")]
+use crate::defmt;
+
+/// Serial error.
+pub trait Error: core::fmt::Debug {
+    /// Convert error to a generic serial error kind
+    ///
+    /// By using this method, serial errors freely defined by HAL implementations
+    /// can be converted to a set of generic serial errors upon which generic
+    /// code can act.
+    fn kind(&self) -> ErrorKind;
+}
+
+impl Error for core::convert::Infallible {
+    #[inline]
+    fn kind(&self) -> ErrorKind {
+        match *self {}
+    }
+}
+
+/// Serial error kind.
+///
+/// This represents a common set of serial operation errors. HAL implementations are
+/// free to define more specific or additional error types. However, by providing
+/// a mapping to these common serial errors, generic code can still react to them.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// The peripheral receive buffer was overrun.
+    Overrun,
+    /// Received data does not conform to the peripheral configuration.
+    /// Can be caused by a misconfigured device on either end of the serial line.
+    FrameFormat,
+    /// Parity check failed.
+    Parity,
+    /// Serial line is too noisy to read valid data.
+    Noise,
+    /// A configuration parameter was invalid, e.g. a baud rate that cannot be achieved
+    /// with the peripheral clock.
+    InvalidInput,
+    /// A different error occurred. The original error may contain more information.
+    Other,
+}
+
+impl Error for ErrorKind {
+    #[inline]
+    fn kind(&self) -> ErrorKind {
+        *self
+    }
+}
+
+impl core::error::Error for ErrorKind {}
+
+impl core::fmt::Display for ErrorKind {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Overrun => write!(f, "The peripheral receive buffer was overrun"),
+            Self::Parity => write!(f, "Parity check failed"),
+            Self::Noise => write!(f, "Serial line is too noisy to read valid data"),
+            Self::InvalidInput => write!(f, "A configuration parameter was invalid"),
+            Self::FrameFormat => write!(
+                f,
+                "Received data does not conform to the peripheral configuration"
+            ),
+            Self::Other => write!(
+                f,
+                "A different error occurred. The original error may contain more information"
+            ),
+        }
+    }
+}
+
+/// Serial error type trait.
+///
+/// This just defines the error type, to be used by the other traits.
+pub trait ErrorType {
+    /// Error type
+    type Error: Error;
+}
+
+impl<T: ErrorType + ?Sized> ErrorType for &mut T {
+    type Error = T::Error;
+}
+
+/// Read half of a serial interface.
+///
+/// Some serial interfaces support different data sizes (8 bits, 9 bits, etc.);
+/// This can be encoded in this trait via the `Word` type parameter.
+pub trait Read<Word: 'static + Copy = u8>: ErrorType {
+    /// Read an exact number of words.
+    ///
+    /// This waits until `read.len()` words have been received.
+    ///
+    /// This function is not side-effect-free on cancel (AKA "cancel-safe"), i.e. if you cancel (drop) a returned
+    /// future that hasn't completed yet, some words might have already been received, which will get lost.
+    async fn read_exact(&mut self, read: &mut [Word]) -> Result<(), Self::Error>;
+}
+
+impl<T: Read<Word> + ?Sized, Word: 'static + Copy> Read<Word> for &mut T {
+    #[inline]
+    async fn read_exact(&mut self, read: &mut [Word]) -> Result<(), Self::Error> {
+        T::read_exact(self, read).await
+    }
+}
+
+/// Read half of a serial interface that can detect when the line goes idle.
+///
+/// This is useful for protocols with variable-length frames delimited by silence on the line,
+/// such as MODBUS RTU.
+pub trait ReadUntilIdle<Word: 'static + Copy = u8>: ErrorType {
+    /// Read words until the line goes idle or `read` is full, returning the amount of words read.
+    ///
+    /// The line is considered idle when no new word has been received for (at least) one word time.
+    ///
+    /// Implementations must not busy-wait: the future yields while waiting for words or for the
+    /// line to go idle (typically using the idle line interrupt of the UART), so the executor can
+    /// run other tasks in the meantime.
+    ///
+    /// This function is not side-effect-free on cancel (AKA "cancel-safe"), i.e. if you cancel (drop) a returned
+    /// future that hasn't completed yet, any words received so far are dropped.
+    async fn read_until_idle(&mut self, read: &mut [Word]) -> Result<usize, Self::Error>;
+}
+
+impl<T: ReadUntilIdle<Word> + ?Sized, Word: 'static + Copy> ReadUntilIdle<Word> for &mut T {
+    #[inline]
+    async fn read_until_idle(&mut self, read: &mut [Word]) -> Result<usize, Self::Error> {
+        T::read_until_idle(self, read).await
+    }
+}
+
+/// Wait for the receive line to go idle.
+///
+/// This is the asynchronous companion of `embedded_hal_nb::serial::IdleLineDetect`: instead of
+/// enabling the idle line interrupt and polling the idle flag, the future completes once the
+/// line has been idle for one frame time after receiving data.
+pub trait IdleLineDetect: ErrorType {
+    /// Wait until an idle line is detected.
+    async fn wait_for_idle(&mut self) -> Result<(), Self::Error>;
+}
+
+impl<T: IdleLineDetect + ?Sized> IdleLineDetect for &mut T {
+    #[inline]
+    async fn wait_for_idle(&mut self) -> Result<(), Self::Error> {
+        T::wait_for_idle(self).await
+    }
+}
+
+/// Wait for a break condition.
+///
+/// This is the asynchronous companion of `embedded_hal_nb::serial::BreakDetect`.
+pub trait BreakDetect: ErrorType {
+    /// Wait until a break condition is detected on the receive line.
+    ///
+    /// Implementations clear the break detection flag before returning.
+    async fn wait_for_break(&mut self) -> Result<(), Self::Error>;
+}
+
+impl<T: BreakDetect + ?Sized> BreakDetect for &mut T {
+    #[inline]
+    async fn wait_for_break(&mut self) -> Result<(), Self::Error> {
+        T::wait_for_break(self).await
+    }
+}
+
+/// Write half of a serial interface.
+pub trait Write<Word: 'static + Copy = u8>: ErrorType {
+    /// Write all words from `buffer`.
+    ///
+    /// This returns once all words have been handed over to the peripheral, they might still be
+    /// in transmission. Use [`flush`](Write::flush) to wait until transmission is complete.
+    async fn write(&mut self, buffer: &[Word]) -> Result<(), Self::Error>;
+
+    /// Ensures that none of the previously written words are still buffered.
+    async fn flush(&mut self) -> Result<(), Self::Error>;
+}
+
+impl<T: Write<Word> + ?Sized, Word: 'static + Copy> Write<Word> for &mut T {
+    #[inline]
+    async fn write(&mut self, buffer: &[Word]) -> Result<(), Self::Error> {
+        T::write(self, buffer).await
+    }
+
+    #[inline]
+    async fn flush(&mut self) -> Result<(), Self::Error> {
+        T::flush(self).await
+    }
+}
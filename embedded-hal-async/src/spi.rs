@@ -26,6 +26,12 @@ pub trait SpiDevice<Word: Copy + 'static = u8>: ErrorType {
     ///
     /// On bus errors the implementation should try to deassert CS.
     /// If an error occurs while deasserting CS the bus error should take priority as the return value.
+    ///
+    /// If the returned future is dropped before completion, the transaction is aborted: some of the
+    /// operations may have been performed. Implementations should make sure CS doesn't stay
+    /// asserted and the bus is left usable, for example by deasserting CS when the future is dropped
+    /// and flushing the bus before the next transaction. Implementations should document whether
+    /// they do so.
     async fn transaction(
         &mut self,
         operations: &mut [Operation<'_, Word>],
@@ -72,6 +78,32 @@ pub trait SpiDevice<Word: Copy + 'static = u8>: ErrorType {
         self.transaction(&mut [Operation::TransferInPlace(buf)])
             .await
     }
+
+    /// Do a read within a transaction, sending `fill` on MOSI for every word read.
+    ///
+    /// Unlike [`read`](SpiDevice::read), where the word sent on MOSI is implementation-defined, this
+    /// guarantees the value sent. `buf` is filled with `fill`, then used for an in-place transfer,
+    /// so no separate write buffer is needed.
+    ///
+    /// This is a convenience method equivalent to `buf.fill(fill); device.transfer_in_place(buf)`.
+    ///
+    /// See also: [`SpiDevice::transfer_in_place`]
+    #[inline]
+    async fn read_with_fill(&mut self, buf: &mut [Word], fill: Word) -> Result<(), Self::Error> {
+        buf.fill(fill);
+        self.transfer_in_place(buf).await
+    }
+
+    /// Do a read within a transaction, sending `Word::default()` (zero for integers) on MOSI for every word read.
+    ///
+    /// This is a convenience method equivalent to `device.read_with_fill(buf, Word::default())`.
+    #[inline]
+    async fn read_zeroed(&mut self, buf: &mut [Word]) -> Result<(), Self::Error>
+    where
+        Word: Default,
+    {
+        self.read_with_fill(buf, Word::default()).await
+    }
 }
 
 impl<Word: Copy + 'static, T: SpiDevice<Word> + ?Sized> SpiDevice<Word> for &mut T {
@@ -102,6 +134,19 @@ impl<Word: Copy + 'static, T: SpiDevice<Word> + ?Sized> SpiDevice<Word> for &mut
     async fn transfer_in_place(&mut self, buf: &mut [Word]) -> Result<(), Self::Error> {
         T::transfer_in_place(self, buf).await
     }
+
+    #[inline]
+    async fn read_with_fill(&mut self, buf: &mut [Word], fill: Word) -> Result<(), Self::Error> {
+        T::read_with_fill(self, buf, fill).await
+    }
+
+    #[inline]
+    async fn read_zeroed(&mut self, buf: &mut [Word]) -> Result<(), Self::Error>
+    where
+        Word: Default,
+    {
+        T::read_zeroed(self, buf).await
+    }
 }
 
 /// SPI bus.
@@ -138,6 +183,31 @@ pub trait SpiBus<Word: 'static + Copy = u8>: ErrorType {
     /// complete. See [the docs on embedded-hal][embedded_hal::spi] for details on flushing.
     async fn transfer(&mut self, read: &mut [Word], write: &[Word]) -> Result<(), Self::Error>;
 
+    /// Write and read simultaneously, like [`transfer`](SpiBus::transfer), for at most `n` words.
+    ///
+    /// The transfer runs for at most `min(n, max(read.len(), write.len()))` words, and returns the
+    /// number of words actually transferred. Implementations may stop early without error, e.g.
+    /// when the slave signals it has no more data by deasserting a data-ready line.
+    ///
+    /// The default implementation never stops early: it calls [`transfer`](SpiBus::transfer)
+    /// with the buffers truncated to `n` words.
+    ///
+    /// Implementations are allowed to return before the operation is
+    /// complete. See [the docs on embedded-hal][embedded_hal::spi] for details on flushing.
+    async fn transfer_n(
+        &mut self,
+        read: &mut [Word],
+        write: &[Word],
+        n: usize,
+    ) -> Result<usize, Self::Error> {
+        let n = n.min(read.len().max(write.len()));
+        let read_len = n.min(read.len());
+        let write_len = n.min(write.len());
+        self.transfer(&mut read[..read_len], &write[..write_len])
+            .await?;
+        Ok(n)
+    }
+
     /// Write and read simultaneously. The contents of `words` are
     /// written to the slave, and the received words are stored into the same
     /// `words` buffer, overwriting it.
@@ -150,6 +220,25 @@ pub trait SpiBus<Word: 'static + Copy = u8>: ErrorType {
     ///
     /// See [the docs on embedded-hal][embedded_hal::spi] for information on flushing.
     async fn flush(&mut self) -> Result<(), Self::Error>;
+
+    /// Wait until all operations have completed, then delay for at least `ns` nanoseconds.
+    ///
+    /// This allows expressing delays between operations, such as inter-byte delays, at the bus level.
+    /// `delay_ns(0)` must be a no-op.
+    ///
+    /// # Default implementation
+    ///
+    /// **The default implementation performs no delay at all**: since a bus doesn't necessarily
+    /// have access to a time source, it only waits for completion with [`flush`](SpiBus::flush),
+    /// and returns immediately. Implementations able to delay must override it. Drivers requiring
+    /// an actual delay should use [`SpiDevice`] with [`Operation::DelayNs`] instead, unless they
+    /// know the bus implements this method.
+    async fn delay_ns(&mut self, ns: u32) -> Result<(), Self::Error> {
+        if ns != 0 {
+            self.flush().await?;
+        }
+        Ok(())
+    }
 }
 
 impl<T: SpiBus<Word> + ?Sized, Word: 'static + Copy> SpiBus<Word> for &mut T {
@@ -168,6 +257,16 @@ impl<T: SpiBus<Word> + ?Sized, Word: 'static + Copy> SpiBus<Word> for &mut T {
         T::transfer(self, read, write).await
     }
 
+    #[inline]
+    async fn transfer_n(
+        &mut self,
+        read: &mut [Word],
+        write: &[Word],
+        n: usize,
+    ) -> Result<usize, Self::Error> {
+        T::transfer_n(self, read, write, n).await
+    }
+
     #[inline]
     async fn transfer_in_place(&mut self, words: &mut [Word]) -> Result<(), Self::Error> {
         T::transfer_in_place(self, words).await
@@ -177,4 +276,9 @@ impl<T: SpiBus<Word> + ?Sized, Word: 'static + Copy> SpiBus<Word> for &mut T {
     async fn flush(&mut self) -> Result<(), Self::Error> {
         T::flush(self).await
     }
+
+    #[inline]
+    async fn delay_ns(&mut self, ns: u32) -> Result<(), Self::Error> {
+        T::delay_ns(self, ns).await
+    }
 }
@@ -0,0 +1,70 @@
+use core::marker::PhantomData;
+use embedded_hal::i2c::{ErrorType, I2c};
+
+use crate::util::HalBusMutex;
+
+/// Shared bus [`I2c`] implementation over any [`HalBusMutex`].
+///
+/// This allows sharing a bus using a mutex type not directly supported by this crate, such as
+/// an RTOS mutex, by implementing [`HalBusMutex`] for it. The mutex is locked for the entire
+/// duration of each transaction.
+pub struct HalBusMutexDevice<'a, M, T> {
+    bus: &'a M,
+    _bus: PhantomData<fn() -> T>,
+}
+
+impl<'a, M, T> HalBusMutexDevice<'a, M, T>
+where
+    M: HalBusMutex<T>,
+{
+    /// Create a new `HalBusMutexDevice`.
+    #[inline]
+    pub fn new(bus: &'a M) -> Self {
+        Self {
+            bus,
+            _bus: PhantomData,
+        }
+    }
+}
+
+impl<M, T> ErrorType for HalBusMutexDevice<'_, M, T>
+where
+    T: I2c,
+{
+    type Error = T::Error;
+}
+
+impl<M, T> I2c for HalBusMutexDevice<'_, M, T>
+where
+    M: HalBusMutex<T>,
+    T: I2c,
+{
+    #[inline]
+    fn read(&mut self, address: u8, read: &mut [u8]) -> Result<(), Self::Error> {
+        self.bus.lock(|bus| bus.read(address, read))
+    }
+
+    #[inline]
+    fn write(&mut self, address: u8, write: &[u8]) -> Result<(), Self::Error> {
+        self.bus.lock(|bus| bus.write(address, write))
+    }
+
+    #[inline]
+    fn write_read(
+        &mut self,
+        address: u8,
+        write: &[u8],
+        read: &mut [u8],
+    ) -> Result<(), Self::Error> {
+        self.bus.lock(|bus| bus.write_read(address, write, read))
+    }
+
+    #[inline]
+    fn transaction(
+        &mut self,
+        address: u8,
+        operations: &mut [embedded_hal::i2c::Operation<'_>],
+    ) -> Result<(), Self::Error> {
+        self.bus.lock(|bus| bus.transaction(address, operations))
+    }
+}
@@ -0,0 +1,184 @@
+use embedded_hal::i2c::{AddressMode, Error, ErrorType, I2c, Operation, SevenBitAddress};
+
+/// [`I2c`] wrapper mapping the errors of the wrapped bus or device with a closure.
+///
+/// Drivers usually wrap the bus errors in their own error type. Instead of implementing
+/// `From<I2c::Error>` for it, the conversion can be done when creating the bus, so that the driver
+/// can use the `?` operator directly.
+///
+/// This implements both the blocking and, with the `async` feature, the async `I2c` trait.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_hal::i2c::{Error, ErrorKind, I2c};
+/// use embedded_hal_bus::i2c::I2cExt;
+/// # use embedded_hal::i2c::{ErrorType, Operation};
+/// # struct I2c0;
+/// # impl ErrorType for I2c0 {
+/// #     type Error = ErrorKind;
+/// # }
+/// # impl I2c for I2c0 {
+/// #     fn transaction(&mut self, address: u8, operations: &mut [Operation<'_>]) -> Result<(), Self::Error> {
+/// #         Err(ErrorKind::Bus)
+/// #     }
+/// # }
+/// # let i2c = I2c0;
+///
+/// #[derive(Debug, PartialEq)]
+/// enum SensorError {
+///     Bus(ErrorKind),
+///     NotFound,
+/// }
+///
+/// impl Error for SensorError {
+///     fn kind(&self) -> ErrorKind {
+///         match self {
+///             SensorError::Bus(kind) => *kind,
+///             SensorError::NotFound => ErrorKind::Other,
+///         }
+///     }
+/// }
+///
+/// let mut i2c = i2c.map_err(|e| SensorError::Bus(e.kind()));
+/// assert_eq!(i2c.write(0x48, &[0x01]), Err(SensorError::Bus(ErrorKind::Bus)));
+/// ```
+pub struct MapErrorI2c<I, F> {
+    i2c: I,
+    f: F,
+}
+
+impl<I, F> MapErrorI2c<I, F> {
+    /// Creates a new `MapErrorI2c`, mapping the errors of `i2c` with `f`.
+    #[inline]
+    pub fn new(i2c: I, f: F) -> Self {
+        Self { i2c, f }
+    }
+
+    /// Returns a reference to the wrapped bus or device.
+    #[inline]
+    pub fn inner(&self) -> &I {
+        &self.i2c
+    }
+
+    /// Returns a mutable reference to the wrapped bus or device.
+    #[inline]
+    pub fn inner_mut(&mut self) -> &mut I {
+        &mut self.i2c
+    }
+
+    /// Consumes the `MapErrorI2c`, returning the wrapped bus or device and the closure.
+    #[inline]
+    pub fn into_inner(self) -> (I, F) {
+        (self.i2c, self.f)
+    }
+}
+
+impl<I, E, F> ErrorType for MapErrorI2c<I, F>
+where
+    I: ErrorType,
+    E: Error,
+    F: FnMut(I::Error) -> E,
+{
+    type Error = E;
+}
+
+impl<A, I, E, F> I2c<A> for MapErrorI2c<I, F>
+where
+    A: AddressMode,
+    I: I2c<A>,
+    E: Error,
+    F: FnMut(I::Error) -> E,
+{
+    #[inline]
+    fn read(&mut self, address: A, read: &mut [u8]) -> Result<(), Self::Error> {
+        self.i2c.read(address, read).map_err(&mut self.f)
+    }
+
+    #[inline]
+    fn write(&mut self, address: A, write: &[u8]) -> Result<(), Self::Error> {
+        self.i2c.write(address, write).map_err(&mut self.f)
+    }
+
+    #[inline]
+    fn write_read(&mut self, address: A, write: &[u8], read: &mut [u8]) -> Result<(), Self::Error> {
+        self.i2c
+            .write_read(address, write, read)
+            .map_err(&mut self.f)
+    }
+
+    #[inline]
+    fn transaction(
+        &mut self,
+        address: A,
+        operations: &mut [Operation<'_>],
+    ) -> Result<(), Self::Error> {
+        self.i2c
+            .transaction(address, operations)
+            .map_err(&mut self.f)
+    }
+}
+
+#[cfg(feature = "async")]
+#[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+impl<A, I, E, F> embedded_hal_async::i2c::I2c<A> for MapErrorI2c<I, F>
+where
+    A: AddressMode,
+    I: embedded_hal_async::i2c::I2c<A>,
+    E: Error,
+    F: FnMut(I::Error) -> E,
+{
+    #[inline]
+    async fn read(&mut self, address: A, read: &mut [u8]) -> Result<(), Self::Error> {
+        self.i2c.read(address, read).await.map_err(&mut self.f)
+    }
+
+    #[inline]
+    async fn write(&mut self, address: A, write: &[u8]) -> Result<(), Self::Error> {
+        self.i2c.write(address, write).await.map_err(&mut self.f)
+    }
+
+    #[inline]
+    async fn write_read(
+        &mut self,
+        address: A,
+        write: &[u8],
+        read: &mut [u8],
+    ) -> Result<(), Self::Error> {
+        self.i2c
+            .write_read(address, write, read)
+            .await
+            .map_err(&mut self.f)
+    }
+
+    #[inline]
+    async fn transaction(
+        &mut self,
+        address: A,
+        operations: &mut [Operation<'_>],
+    ) -> Result<(), Self::Error> {
+        self.i2c
+            .transaction(address, operations)
+            .await
+            .map_err(&mut self.f)
+    }
+}
+
+/// Extension trait adding adapter methods to all [`I2c`] implementations.
+///
+/// For async-only implementations, use [`MapErrorI2c::new`] directly.
+pub trait I2cExt<A: AddressMode = SevenBitAddress>: I2c<A> + Sized {
+    /// Maps the errors of this bus or device with `f`.
+    ///
+    /// See [`MapErrorI2c`].
+    #[inline]
+    fn map_err<E, F>(self, f: F) -> MapErrorI2c<Self, F>
+    where
+        E: Error,
+        F: FnMut(Self::Error) -> E,
+    {
+        MapErrorI2c::new(self, f)
+    }
+}
+
+impl<A: AddressMode, T: I2c<A>> I2cExt<A> for T {}
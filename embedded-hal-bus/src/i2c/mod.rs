@@ -8,6 +8,18 @@ mod mutex;
 pub use mutex::*;
 mod critical_section;
 pub use self::critical_section::*;
+mod hal_bus_mutex;
+pub use hal_bus_mutex::*;
+mod retry;
+pub use retry::*;
+mod map_err;
+pub use map_err::*;
+mod throttled;
+pub use throttled::*;
+#[cfg(feature = "async")]
+mod timeout;
+#[cfg(feature = "async")]
+pub use timeout::*;
 #[cfg(any(feature = "portable-atomic", target_has_atomic = "8"))]
 mod atomic;
 #[cfg(any(feature = "portable-atomic", target_has_atomic = "8"))]
@@ -8,8 +8,9 @@ use embedded_hal::i2c::{ErrorType, I2c};
 /// This is the reference-counting equivalent of [`RefCellDevice`](super::RefCellDevice).
 ///
 /// Sharing is implemented with a [`RefCell`] and ownership is managed by [`Rc`].
-/// Like [`RefCellDevice`](super::RefCellDevice), `RcDevice` instances are not [`Send`],
-/// so they can only be shared within a single thread (interrupt priority level).
+/// Like [`RefCellDevice`](super::RefCellDevice), `RcDevice` instances are neither [`Send`] nor
+/// [`Sync`], so they can only be shared within a single thread (interrupt priority level).
+/// For sharing a bus between threads, use `MutexDevice` (requires the `std` feature) instead.
 ///
 /// When this `RcDevice` is dropped, the reference count of the I2C bus will be decremented.
 /// Once that reference count hits zero, it will be cleaned up.
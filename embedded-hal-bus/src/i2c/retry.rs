@@ -0,0 +1,137 @@
+use embedded_hal::delay::DelayNs;
+use embedded_hal::i2c::{AddressMode, Error, ErrorKind, ErrorType, I2c, NoAcknowledgeSource};
+
+/// [`I2c`] wrapper retrying operations not acknowledged by the device.
+///
+/// I2C EEPROM and FRAM chips don't acknowledge their address while an internal write cycle is in
+/// progress, so the usual way to wait for the end of a write is to poll the device until it
+/// responds. `RetryI2cDevice` does this transparently: operations failing with an error of kind
+/// [`ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address)`](ErrorKind::NoAcknowledge) are
+/// retried up to `max_retries` times, waiting `retry_delay_us` microseconds between attempts.
+/// Other errors are returned immediately.
+///
+/// The whole transaction is retried. If the address is not acknowledged at its first start
+/// condition, no data has been transferred. However, the address is sent again at each repeated
+/// start, so for [`write_read`](I2c::write_read) and multi-operation transactions, the writes
+/// preceding the failure may already have reached the device and are repeated. Only use this
+/// wrapper with devices for which repeating these writes is harmless, such as writes setting a
+/// register or memory address.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_hal_bus::i2c::RetryI2cDevice;
+/// # use embedded_hal::delay::DelayNs;
+/// # use embedded_hal::i2c::{ErrorKind, ErrorType, I2c, Operation};
+/// # struct I2c0;
+/// # impl ErrorType for I2c0 {
+/// #     type Error = ErrorKind;
+/// # }
+/// # impl I2c for I2c0 {
+/// #     fn transaction(&mut self, address: u8, operations: &mut [Operation<'_>]) -> Result<(), Self::Error> {
+/// #         Ok(())
+/// #     }
+/// # }
+/// # struct Delay;
+/// # impl DelayNs for Delay {
+/// #     fn delay_ns(&mut self, _ns: u32) {}
+/// # }
+/// # let (i2c, delay) = (I2c0, Delay);
+///
+/// // Poll an EEPROM for up to 10 ms after a write.
+/// let mut eeprom = RetryI2cDevice::new(i2c, delay, 100, 100);
+/// eeprom.write(0x50, &[0x00, 0x10, 0xAB]).unwrap();
+/// // Retried until the write cycle ends.
+/// eeprom.write(0x50, &[0x00, 0x11, 0xCD]).unwrap();
+/// ```
+pub struct RetryI2cDevice<D, DEL> {
+    device: D,
+    delay: DEL,
+    max_retries: u8,
+    retry_delay_us: u32,
+}
+
+impl<D, DEL> RetryI2cDevice<D, DEL> {
+    /// Creates a new `RetryI2cDevice`.
+    ///
+    /// Operations are attempted at most `1 + max_retries` times, waiting `retry_delay_us`
+    /// microseconds between attempts.
+    #[inline]
+    pub fn new(device: D, delay: DEL, max_retries: u8, retry_delay_us: u32) -> Self {
+        Self {
+            device,
+            delay,
+            max_retries,
+            retry_delay_us,
+        }
+    }
+
+    /// Returns a reference to the underlying device.
+    #[inline]
+    pub fn device(&self) -> &D {
+        &self.device
+    }
+
+    /// Returns a mutable reference to the underlying device.
+    #[inline]
+    pub fn device_mut(&mut self) -> &mut D {
+        &mut self.device
+    }
+
+    /// Consumes the `RetryI2cDevice`, returning the underlying device and delay.
+    #[inline]
+    pub fn into_inner(self) -> (D, DEL) {
+        (self.device, self.delay)
+    }
+}
+
+impl<D: ErrorType, DEL: DelayNs> RetryI2cDevice<D, DEL> {
+    fn retry<R>(
+        &mut self,
+        mut f: impl FnMut(&mut D) -> Result<R, D::Error>,
+    ) -> Result<R, D::Error> {
+        let mut retries = 0;
+        loop {
+            match f(&mut self.device) {
+                Err(e)
+                    if retries < self.max_retries
+                        && e.kind() == ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address) =>
+                {
+                    retries += 1;
+                    self.delay.delay_us(self.retry_delay_us);
+                }
+                res => return res,
+            }
+        }
+    }
+}
+
+impl<D: ErrorType, DEL> ErrorType for RetryI2cDevice<D, DEL> {
+    type Error = D::Error;
+}
+
+impl<A: AddressMode, D: I2c<A>, DEL: DelayNs> I2c<A> for RetryI2cDevice<D, DEL> {
+    #[inline]
+    fn read(&mut self, address: A, read: &mut [u8]) -> Result<(), Self::Error> {
+        self.retry(|device| device.read(address, read))
+    }
+
+    #[inline]
+    fn write(&mut self, address: A, write: &[u8]) -> Result<(), Self::Error> {
+        self.retry(|device| device.write(address, write))
+    }
+
+    #[inline]
+    fn write_read(&mut self, address: A, write: &[u8], read: &mut [u8]) -> Result<(), Self::Error> {
+        self.retry(|device| device.write_read(address, write, read))
+    }
+
+    #[inline]
+    fn transaction(
+        &mut self,
+        address: A,
+        operations: &mut [embedded_hal::i2c::Operation<'_>],
+    ) -> Result<(), Self::Error> {
+        self.retry(|device| device.transaction(address, operations))
+    }
+}
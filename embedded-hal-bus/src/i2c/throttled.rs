@@ -0,0 +1,213 @@
+use embedded_hal::delay::DelayNs;
+use embedded_hal::i2c::{AddressMode, ErrorType, I2c, Operation};
+#[cfg(feature = "async")]
+use embedded_hal_async::{delay::DelayNs as AsyncDelayNs, i2c::I2c as AsyncI2c};
+
+/// [`I2c`] wrapper enforcing a minimum interval between transactions.
+///
+/// When several devices share a bus, a driver polling its device in a tight loop can monopolize
+/// the bus and cause timeouts in the other drivers. `ThrottledI2cDevice` waits, if needed, so that
+/// at least `min_interval_us` microseconds elapse between the end of an operation and the start of
+/// the next one.
+///
+/// The time is read from `clock`, a closure returning a timestamp in microseconds, which is allowed
+/// to wrap around. It is typically implemented by reading a free-running hardware timer. The
+/// elapsed time is computed modulo 2<sup>32</sup> µs, so it wraps after about 71.6 minutes: if the
+/// bus was idle for longer, the next operation may be delayed by up to `min_interval_us`
+/// microseconds.
+///
+/// This implements both the blocking and, with the `async` feature, the async `I2c` trait,
+/// depending on the traits implemented by the device and the delay.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_hal_bus::i2c::ThrottledI2cDevice;
+/// # use embedded_hal::delay::DelayNs;
+/// # use embedded_hal::i2c::{ErrorKind, ErrorType, I2c, Operation};
+/// # struct I2c0;
+/// # impl ErrorType for I2c0 {
+/// #     type Error = ErrorKind;
+/// # }
+/// # impl I2c for I2c0 {
+/// #     fn transaction(&mut self, address: u8, operations: &mut [Operation<'_>]) -> Result<(), Self::Error> {
+/// #         Ok(())
+/// #     }
+/// # }
+/// # struct Delay;
+/// # impl DelayNs for Delay {
+/// #     fn delay_ns(&mut self, _ns: u32) {}
+/// # }
+/// # fn timer_us() -> u32 { 0 }
+/// # let (i2c, delay) = (I2c0, Delay);
+///
+/// // Leave the bus idle for at least 1 ms between operations.
+/// let mut sensor = ThrottledI2cDevice::new(i2c, delay, timer_us, 1_000);
+/// let mut buf = [0; 2];
+/// sensor.write_read(0x48, &[0x00], &mut buf).unwrap();
+/// // Delayed if less than 1 ms has elapsed since the previous operation.
+/// sensor.write_read(0x48, &[0x00], &mut buf).unwrap();
+/// ```
+pub struct ThrottledI2cDevice<D, DEL, CLK> {
+    device: D,
+    delay: DEL,
+    clock: CLK,
+    min_interval_us: u32,
+    last_us: Option<u32>,
+}
+
+impl<D, DEL, CLK: FnMut() -> u32> ThrottledI2cDevice<D, DEL, CLK> {
+    /// Creates a new `ThrottledI2cDevice`.
+    ///
+    /// `clock` returns the current time in microseconds. The first operation is never delayed.
+    #[inline]
+    pub fn new(device: D, delay: DEL, clock: CLK, min_interval_us: u32) -> Self {
+        Self {
+            device,
+            delay,
+            clock,
+            min_interval_us,
+            last_us: None,
+        }
+    }
+
+    /// Returns a reference to the underlying device.
+    #[inline]
+    pub fn device(&self) -> &D {
+        &self.device
+    }
+
+    /// Returns a mutable reference to the underlying device.
+    ///
+    /// Operations done directly on the device are not throttled.
+    #[inline]
+    pub fn device_mut(&mut self) -> &mut D {
+        &mut self.device
+    }
+
+    /// Consumes the `ThrottledI2cDevice`, returning the underlying device, delay and clock.
+    #[inline]
+    pub fn into_inner(self) -> (D, DEL, CLK) {
+        (self.device, self.delay, self.clock)
+    }
+
+    /// Returns the time to wait before the next operation, in microseconds.
+    fn remaining_us(&mut self) -> u32 {
+        match self.last_us {
+            Some(last) => self
+                .min_interval_us
+                .saturating_sub((self.clock)().wrapping_sub(last)),
+            None => 0,
+        }
+    }
+
+    fn throttle<R>(&mut self, f: impl FnOnce(&mut D) -> R) -> R
+    where
+        DEL: DelayNs,
+    {
+        let remaining = self.remaining_us();
+        if remaining > 0 {
+            self.delay.delay_us(remaining);
+        }
+        let res = f(&mut self.device);
+        self.last_us = Some((self.clock)());
+        res
+    }
+}
+
+impl<D: ErrorType, DEL, CLK> ErrorType for ThrottledI2cDevice<D, DEL, CLK> {
+    type Error = D::Error;
+}
+
+impl<A, D, DEL, CLK> I2c<A> for ThrottledI2cDevice<D, DEL, CLK>
+where
+    A: AddressMode,
+    D: I2c<A>,
+    DEL: DelayNs,
+    CLK: FnMut() -> u32,
+{
+    #[inline]
+    fn read(&mut self, address: A, read: &mut [u8]) -> Result<(), Self::Error> {
+        self.throttle(|device| device.read(address, read))
+    }
+
+    #[inline]
+    fn write(&mut self, address: A, write: &[u8]) -> Result<(), Self::Error> {
+        self.throttle(|device| device.write(address, write))
+    }
+
+    #[inline]
+    fn write_read(&mut self, address: A, write: &[u8], read: &mut [u8]) -> Result<(), Self::Error> {
+        self.throttle(|device| device.write_read(address, write, read))
+    }
+
+    #[inline]
+    fn transaction(
+        &mut self,
+        address: A,
+        operations: &mut [Operation<'_>],
+    ) -> Result<(), Self::Error> {
+        self.throttle(|device| device.transaction(address, operations))
+    }
+}
+
+#[cfg(feature = "async")]
+#[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+impl<A, D, DEL, CLK> AsyncI2c<A> for ThrottledI2cDevice<D, DEL, CLK>
+where
+    A: AddressMode,
+    D: AsyncI2c<A>,
+    DEL: AsyncDelayNs,
+    CLK: FnMut() -> u32,
+{
+    #[inline]
+    async fn read(&mut self, address: A, read: &mut [u8]) -> Result<(), Self::Error> {
+        self.wait().await;
+        let res = self.device.read(address, read).await;
+        self.last_us = Some((self.clock)());
+        res
+    }
+
+    #[inline]
+    async fn write(&mut self, address: A, write: &[u8]) -> Result<(), Self::Error> {
+        self.wait().await;
+        let res = self.device.write(address, write).await;
+        self.last_us = Some((self.clock)());
+        res
+    }
+
+    #[inline]
+    async fn write_read(
+        &mut self,
+        address: A,
+        write: &[u8],
+        read: &mut [u8],
+    ) -> Result<(), Self::Error> {
+        self.wait().await;
+        let res = self.device.write_read(address, write, read).await;
+        self.last_us = Some((self.clock)());
+        res
+    }
+
+    #[inline]
+    async fn transaction(
+        &mut self,
+        address: A,
+        operations: &mut [Operation<'_>],
+    ) -> Result<(), Self::Error> {
+        self.wait().await;
+        let res = self.device.transaction(address, operations).await;
+        self.last_us = Some((self.clock)());
+        res
+    }
+}
+
+#[cfg(feature = "async")]
+impl<D, DEL: AsyncDelayNs, CLK: FnMut() -> u32> ThrottledI2cDevice<D, DEL, CLK> {
+    async fn wait(&mut self) {
+        let remaining = self.remaining_us();
+        if remaining > 0 {
+            self.delay.delay_us(remaining).await;
+        }
+    }
+}
@@ -0,0 +1,171 @@
+use core::future::{poll_fn, Future};
+use core::pin::pin;
+use core::task::Poll;
+
+use embedded_hal::i2c::{AddressMode, Error, ErrorKind, ErrorType, Operation};
+use embedded_hal_async::{delay::DelayNs, i2c::I2c};
+
+/// Async [`I2c`] wrapper aborting operations which don't complete in time.
+///
+/// A device stretching the clock forever, or a bus stuck low, can make an I2C operation hang
+/// indefinitely. `TimeoutI2cDevice` races each operation against `delay`: if the operation
+/// doesn't complete within `timeout_us` microseconds, its future is dropped, and
+/// [`TimeoutError::TimedOut`] is returned.
+///
+/// Aborting relies on the cancellation support of the underlying device: check that dropping an
+/// operation in progress leaves its peripheral usable. After a timeout, the device on the bus may
+/// still be in the middle of a transaction, and need a bus recovery sequence.
+///
+/// Only the async `I2c` trait is implemented: a blocking operation can't be interrupted, and
+/// splitting a transaction to check the time between its operations would break its atomicity.
+/// Blocking HALs should provide timeouts themselves.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_hal_async::delay::DelayNs;
+/// use embedded_hal_async::i2c::I2c;
+/// use embedded_hal_bus::i2c::{TimeoutError, TimeoutI2cDevice};
+///
+/// async fn read_page<I: I2c, D: DelayNs>(
+///     i2c: I,
+///     delay: D,
+///     page: &mut [u8; 64],
+/// ) -> Result<(), TimeoutError<I::Error>> {
+///     // Give up if the EEPROM doesn't answer within 10 ms.
+///     let mut eeprom = TimeoutI2cDevice::new(i2c, delay, 10_000);
+///     eeprom.write_read(0x50, &[0x00, 0x00], page).await
+/// }
+/// ```
+pub struct TimeoutI2cDevice<D, DEL> {
+    device: D,
+    delay: DEL,
+    timeout_us: u32,
+}
+
+impl<D, DEL> TimeoutI2cDevice<D, DEL> {
+    /// Creates a new `TimeoutI2cDevice`, aborting operations after `timeout_us` microseconds.
+    #[inline]
+    pub fn new(device: D, delay: DEL, timeout_us: u32) -> Self {
+        Self {
+            device,
+            delay,
+            timeout_us,
+        }
+    }
+
+    /// Returns a reference to the underlying device.
+    #[inline]
+    pub fn device(&self) -> &D {
+        &self.device
+    }
+
+    /// Returns a mutable reference to the underlying device.
+    ///
+    /// Operations done directly on the device have no timeout.
+    #[inline]
+    pub fn device_mut(&mut self) -> &mut D {
+        &mut self.device
+    }
+
+    /// Consumes the `TimeoutI2cDevice`, returning the underlying device and delay.
+    #[inline]
+    pub fn into_inner(self) -> (D, DEL) {
+        (self.device, self.delay)
+    }
+}
+
+/// Error type of [`TimeoutI2cDevice`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum TimeoutError<T: Error> {
+    /// The operation didn't complete in time, and was aborted.
+    TimedOut,
+
+    /// An I2C-related error occurred, and the internal error should be inspected.
+    Other(T),
+}
+
+impl<T: Error> Error for TimeoutError<T> {
+    #[inline]
+    fn kind(&self) -> ErrorKind {
+        match self {
+            Self::TimedOut => ErrorKind::TimedOut,
+            Self::Other(e) => e.kind(),
+        }
+    }
+}
+
+impl<T: Error + core::fmt::Display> core::fmt::Display for TimeoutError<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::TimedOut => write!(f, "I2C operation timed out"),
+            Self::Other(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl<T: Error + core::fmt::Display> core::error::Error for TimeoutError<T> {}
+
+impl<D: ErrorType, DEL> ErrorType for TimeoutI2cDevice<D, DEL> {
+    type Error = TimeoutError<D::Error>;
+}
+
+/// Runs `op`, aborting it if `delay` completes first.
+async fn with_timeout<E: Error>(
+    delay: &mut impl DelayNs,
+    timeout_us: u32,
+    op: impl Future<Output = Result<(), E>>,
+) -> Result<(), TimeoutError<E>> {
+    let mut op = pin!(op);
+    let mut timeout = pin!(delay.delay_us(timeout_us));
+    poll_fn(|cx| {
+        if let Poll::Ready(res) = op.as_mut().poll(cx) {
+            return Poll::Ready(res.map_err(TimeoutError::Other));
+        }
+        if timeout.as_mut().poll(cx).is_ready() {
+            return Poll::Ready(Err(TimeoutError::TimedOut));
+        }
+        Poll::Pending
+    })
+    .await
+}
+
+impl<A, D, DEL> I2c<A> for TimeoutI2cDevice<D, DEL>
+where
+    A: AddressMode,
+    D: I2c<A>,
+    DEL: DelayNs,
+{
+    #[inline]
+    async fn read(&mut self, address: A, read: &mut [u8]) -> Result<(), Self::Error> {
+        let op = self.device.read(address, read);
+        with_timeout(&mut self.delay, self.timeout_us, op).await
+    }
+
+    #[inline]
+    async fn write(&mut self, address: A, write: &[u8]) -> Result<(), Self::Error> {
+        let op = self.device.write(address, write);
+        with_timeout(&mut self.delay, self.timeout_us, op).await
+    }
+
+    #[inline]
+    async fn write_read(
+        &mut self,
+        address: A,
+        write: &[u8],
+        read: &mut [u8],
+    ) -> Result<(), Self::Error> {
+        let op = self.device.write_read(address, write, read);
+        with_timeout(&mut self.delay, self.timeout_us, op).await
+    }
+
+    #[inline]
+    async fn transaction(
+        &mut self,
+        address: A,
+        operations: &mut [Operation<'_>],
+    ) -> Result<(), Self::Error> {
+        let op = self.device.transaction(address, operations);
+        with_timeout(&mut self.delay, self.timeout_us, op).await
+    }
+}
@@ -9,4 +9,7 @@ use defmt_03 as defmt;
 
 pub mod i2c;
 pub mod spi;
+#[cfg(feature = "test-utils")]
+#[cfg_attr(docsrs, doc(cfg(feature = "test-utils")))]
+pub mod test_utils;
 pub mod util;
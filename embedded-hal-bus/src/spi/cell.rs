@@ -0,0 +1,172 @@
+use embedded_hal::delay::DelayNs;
+use embedded_hal::digital::OutputPin;
+use embedded_hal::spi::{Error, ErrorKind, ErrorType, Operation, SpiBus, SpiDevice};
+
+use super::DeviceError;
+use crate::spi::shared::transaction;
+use crate::util::BusyCell;
+
+#[cfg(feature = "defmt-03")]
+use crate::defmt;
+
+/// `Cell`-based shared bus [`SpiDevice`] implementation, for targets without atomics.
+///
+/// This allows for sharing an [`SpiBus`], obtaining multiple [`SpiDevice`] instances,
+/// each with its own `CS` pin.
+///
+/// Sharing is implemented with a [`BusyCell`], which consists of an `UnsafeCell` and a
+/// `Cell<bool>` "locked" flag. Like `AtomicDevice`, it has low
+/// overhead and doesn't use critical sections, but it doesn't require atomic operations, so it is
+/// also available on single-core targets without them. `BusyCell` is not `Sync`, so it only allows
+/// sharing within a single thread (interrupt priority level).
+///
+/// Within a single thread, the bus can only be found locked when a transaction is started while
+/// another one is in progress, e.g. from the `CS` pin or delay implementation. This is a
+/// programming error, so [`transaction`](SpiDevice::transaction) panics in this case.
+/// [`try_transaction`](CellDevice::try_transaction) returns [`CellError::Busy`] instead.
+pub struct CellDevice<'a, BUS, CS, D> {
+    bus: &'a BusyCell<BUS>,
+    cs: CS,
+    delay: D,
+}
+
+/// Error returned by [`CellDevice::try_transaction`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub enum CellError<T: Error> {
+    /// The bus was already locked by another transaction in progress.
+    Busy,
+
+    /// An SPI-related error occurred, and the internal error should be inspected.
+    Other(T),
+}
+
+impl<T: Error> Error for CellError<T> {
+    #[inline]
+    fn kind(&self) -> ErrorKind {
+        match self {
+            Self::Other(e) => e.kind(),
+            Self::Busy => ErrorKind::Other,
+        }
+    }
+}
+
+impl<T: Error + core::fmt::Display> core::fmt::Display for CellError<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Busy => write!(f, "SPI bus busy"),
+            Self::Other(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl<T: Error + core::fmt::Display> core::error::Error for CellError<T> {}
+
+impl<'a, BUS, CS, D> CellDevice<'a, BUS, CS, D> {
+    /// Create a new [`CellDevice`].
+    ///
+    /// This sets the `cs` pin high, and returns an error if that fails. It is recommended
+    /// to set the pin high the moment it's configured as an output, to avoid glitches.
+    #[inline]
+    pub fn new(bus: &'a BusyCell<BUS>, mut cs: CS, delay: D) -> Result<Self, CS::Error>
+    where
+        CS: OutputPin,
+    {
+        cs.set_high()?;
+        Ok(Self { bus, cs, delay })
+    }
+}
+
+impl<'a, BUS, CS> CellDevice<'a, BUS, CS, super::NoDelay> {
+    /// Create a new [`CellDevice`] without support for in-transaction delays.
+    ///
+    /// This sets the `cs` pin high, and returns an error if that fails. It is recommended
+    /// to set the pin high the moment it's configured as an output, to avoid glitches.
+    ///
+    /// **Warning**: The returned instance *technically* doesn't comply with the `SpiDevice`
+    /// contract, which mandates delay support. It is relatively rare for drivers to use
+    /// in-transaction delays, so you might still want to use this method because it's more practical.
+    ///
+    /// Note that a future version of the driver might start using delays, causing your
+    /// code to panic. This wouldn't be considered a breaking change from the driver side, because
+    /// drivers are allowed to assume `SpiDevice` implementations comply with the contract.
+    /// If you feel this risk outweighs the convenience of having `cargo` automatically upgrade
+    /// the driver crate, you might want to pin the driver's version.
+    ///
+    /// # Panics
+    ///
+    /// The returned device will panic if you try to execute a transaction
+    /// that contains any operations of type [`Operation::DelayNs`].
+    #[inline]
+    pub fn new_no_delay(bus: &'a BusyCell<BUS>, mut cs: CS) -> Result<Self, CS::Error>
+    where
+        CS: OutputPin,
+    {
+        cs.set_high()?;
+        Ok(Self {
+            bus,
+            cs,
+            delay: super::NoDelay,
+        })
+    }
+}
+
+impl<BUS, CS, D> CellDevice<'_, BUS, CS, D>
+where
+    BUS: ErrorType,
+    CS: OutputPin,
+{
+    /// Perform a transaction, returning [`CellError::Busy`] if the bus is already locked.
+    ///
+    /// See [`SpiDevice::transaction`].
+    #[inline]
+    pub fn try_transaction<Word: Copy + 'static>(
+        &mut self,
+        operations: &mut [Operation<'_, Word>],
+    ) -> Result<(), CellError<<Self as ErrorType>::Error>>
+    where
+        BUS: SpiBus<Word>,
+        D: DelayNs,
+    {
+        if self.bus.busy.replace(true) {
+            return Err(CellError::Busy);
+        }
+
+        // Safety: the busy flag ensures no other reference to the bus exists, and `BusyCell`
+        // is not `Sync`, so it can't be accessed from another thread.
+        let bus = unsafe { &mut *self.bus.bus.get() };
+
+        let result = transaction(operations, bus, &mut self.delay, &mut self.cs);
+
+        self.bus.busy.set(false);
+
+        result.map_err(CellError::Other)
+    }
+}
+
+impl<BUS, CS, D> ErrorType for CellDevice<'_, BUS, CS, D>
+where
+    BUS: ErrorType,
+    CS: OutputPin,
+{
+    type Error = DeviceError<BUS::Error, CS::Error>;
+}
+
+impl<Word: Copy + 'static, BUS, CS, D> SpiDevice<Word> for CellDevice<'_, BUS, CS, D>
+where
+    BUS: SpiBus<Word>,
+    CS: OutputPin,
+    D: DelayNs,
+{
+    /// # Panics
+    ///
+    /// Panics if the bus is already locked by another transaction.
+    #[inline]
+    fn transaction(&mut self, operations: &mut [Operation<'_, Word>]) -> Result<(), Self::Error> {
+        match self.try_transaction(operations) {
+            Ok(()) => Ok(()),
+            Err(CellError::Busy) => panic!("SPI bus already locked by another transaction"),
+            Err(CellError::Other(e)) => Err(e),
+        }
+    }
+}
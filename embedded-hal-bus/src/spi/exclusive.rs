@@ -16,10 +16,17 @@ use super::DeviceError;
 ///
 /// This is the most straightforward way of obtaining an [`SpiDevice`] from an [`SpiBus`],
 /// ideal for when no sharing is required (only one SPI device is present on the bus).
+///
+/// Async transactions are cancel-safe: if a transaction future is dropped before completion, CS
+/// is deasserted immediately, and the bus is flushed at the start of the next transaction. This
+/// costs nothing for transactions running to completion.
 pub struct ExclusiveDevice<BUS, CS, D> {
     bus: BUS,
     cs: CS,
     delay: D,
+    /// An async transaction was cancelled, and the bus may not be flushed.
+    #[cfg(feature = "async")]
+    flush_pending: bool,
 }
 
 impl<BUS, CS, D> ExclusiveDevice<BUS, CS, D> {
@@ -33,7 +40,13 @@ impl<BUS, CS, D> ExclusiveDevice<BUS, CS, D> {
         CS: OutputPin,
     {
         cs.set_high()?;
-        Ok(Self { bus, cs, delay })
+        Ok(Self {
+            bus,
+            cs,
+            delay,
+            #[cfg(feature = "async")]
+            flush_pending: false,
+        })
     }
 
     /// Returns a reference to the underlying bus object.
@@ -47,6 +60,35 @@ impl<BUS, CS, D> ExclusiveDevice<BUS, CS, D> {
     pub fn bus_mut(&mut self) -> &mut BUS {
         &mut self.bus
     }
+
+    /// Returns a reference to the CS pin.
+    #[inline]
+    pub fn cs(&self) -> &CS {
+        &self.cs
+    }
+
+    /// Create a new [`ExclusiveDevice`] from parts previously released with
+    /// [`into_exclusive`](ExclusiveDevice::into_exclusive).
+    ///
+    /// This is the same as [`new`](ExclusiveDevice::new): it sets the `cs` pin high, and returns
+    /// an error if that fails.
+    #[inline]
+    pub fn from_exclusive(bus: BUS, cs: CS, delay: D) -> Result<Self, CS::Error>
+    where
+        CS: OutputPin,
+    {
+        Self::new(bus, cs, delay)
+    }
+
+    /// Release the bus, CS pin and delay, e.g. to take direct control of the bus when switching to
+    /// a firmware upgrade mode.
+    ///
+    /// Since this takes the device by value, no transaction can be in progress. However, if an
+    /// async transaction was cancelled, the bus may not have been flushed.
+    #[inline]
+    pub fn into_exclusive(self) -> (BUS, CS, D) {
+        (self.bus, self.cs, self.delay)
+    }
 }
 
 impl<BUS, CS> ExclusiveDevice<BUS, CS, super::NoDelay> {
@@ -79,6 +121,8 @@ impl<BUS, CS> ExclusiveDevice<BUS, CS, super::NoDelay> {
             bus,
             cs,
             delay: super::NoDelay,
+            #[cfg(feature = "async")]
+            flush_pending: false,
         })
     }
 }
@@ -116,7 +160,19 @@ where
         &mut self,
         operations: &mut [Operation<'_, Word>],
     ) -> Result<(), Self::Error> {
+        if self.flush_pending {
+            // A cancelled transaction may have left words in flight: they must not be sent to the
+            // device once selected again.
+            self.bus.flush().await.map_err(DeviceError::Spi)?;
+            self.flush_pending = false;
+        }
+
         self.cs.set_low().map_err(DeviceError::Cs)?;
+        let cs = CsGuard {
+            cs: &mut self.cs,
+            flush_pending: &mut self.flush_pending,
+            armed: true,
+        };
 
         let op_res = 'ops: {
             for op in operations {
@@ -125,6 +181,7 @@ where
                     Operation::Write(buf) => self.bus.write(buf).await,
                     Operation::Transfer(read, write) => self.bus.transfer(read, write).await,
                     Operation::TransferInPlace(buf) => self.bus.transfer_in_place(buf).await,
+                    Operation::DelayNs(0) => Ok(()),
                     Operation::DelayNs(ns) => match self.bus.flush().await {
                         Err(e) => Err(e),
                         Ok(()) => {
@@ -142,7 +199,7 @@ where
 
         // On failure, it's important to still flush and deassert CS.
         let flush_res = self.bus.flush().await;
-        let cs_res = self.cs.set_high();
+        let cs_res = cs.release();
 
         op_res.map_err(DeviceError::Spi)?;
         flush_res.map_err(DeviceError::Spi)?;
@@ -151,3 +208,30 @@ where
         Ok(())
     }
 }
+
+/// Deasserts CS if an async transaction is cancelled, and records that the bus must be flushed.
+#[cfg(feature = "async")]
+struct CsGuard<'a, CS: OutputPin> {
+    cs: &'a mut CS,
+    flush_pending: &'a mut bool,
+    armed: bool,
+}
+
+#[cfg(feature = "async")]
+impl<CS: OutputPin> CsGuard<'_, CS> {
+    /// Deasserts CS at the end of a transaction which ran to completion.
+    fn release(mut self) -> Result<(), CS::Error> {
+        self.armed = false;
+        self.cs.set_high()
+    }
+}
+
+#[cfg(feature = "async")]
+impl<CS: OutputPin> Drop for CsGuard<'_, CS> {
+    fn drop(&mut self) {
+        if self.armed {
+            *self.flush_pending = true;
+            let _ = self.cs.set_high();
+        }
+    }
+}
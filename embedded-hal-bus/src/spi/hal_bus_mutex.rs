@@ -0,0 +1,107 @@
+use core::marker::PhantomData;
+use embedded_hal::delay::DelayNs;
+use embedded_hal::digital::OutputPin;
+use embedded_hal::spi::{ErrorType, Operation, SpiBus, SpiDevice};
+
+use super::DeviceError;
+use crate::spi::shared::transaction;
+use crate::util::HalBusMutex;
+
+/// Shared bus [`SpiDevice`] implementation over any [`HalBusMutex`].
+///
+/// This allows for sharing an [`SpiBus`], obtaining multiple [`SpiDevice`] instances,
+/// each with its own `CS` pin.
+///
+/// Sharing is implemented with any mutex type implementing [`HalBusMutex`], which allows using
+/// mutex types not directly supported by this crate, such as an RTOS mutex. The mutex is locked
+/// for the entire duration of each transaction.
+pub struct HalBusMutexDevice<'a, M, BUS, CS, D> {
+    bus: &'a M,
+    cs: CS,
+    delay: D,
+    _bus: PhantomData<fn() -> BUS>,
+}
+
+impl<'a, M, BUS, CS, D> HalBusMutexDevice<'a, M, BUS, CS, D>
+where
+    M: HalBusMutex<BUS>,
+{
+    /// Create a new [`HalBusMutexDevice`].
+    ///
+    /// This sets the `cs` pin high, and returns an error if that fails. It is recommended
+    /// to set the pin high the moment it's configured as an output, to avoid glitches.
+    #[inline]
+    pub fn new(bus: &'a M, mut cs: CS, delay: D) -> Result<Self, CS::Error>
+    where
+        CS: OutputPin,
+    {
+        cs.set_high()?;
+        Ok(Self {
+            bus,
+            cs,
+            delay,
+            _bus: PhantomData,
+        })
+    }
+}
+
+impl<'a, M, BUS, CS> HalBusMutexDevice<'a, M, BUS, CS, super::NoDelay>
+where
+    M: HalBusMutex<BUS>,
+{
+    /// Create a new [`HalBusMutexDevice`] without support for in-transaction delays.
+    ///
+    /// This sets the `cs` pin high, and returns an error if that fails. It is recommended
+    /// to set the pin high the moment it's configured as an output, to avoid glitches.
+    ///
+    /// **Warning**: The returned instance *technically* doesn't comply with the `SpiDevice`
+    /// contract, which mandates delay support. It is relatively rare for drivers to use
+    /// in-transaction delays, so you might still want to use this method because it's more practical.
+    ///
+    /// Note that a future version of the driver might start using delays, causing your
+    /// code to panic. This wouldn't be considered a breaking change from the driver side, because
+    /// drivers are allowed to assume `SpiDevice` implementations comply with the contract.
+    /// If you feel this risk outweighs the convenience of having `cargo` automatically upgrade
+    /// the driver crate, you might want to pin the driver's version.
+    ///
+    /// # Panics
+    ///
+    /// The returned device will panic if you try to execute a transaction
+    /// that contains any operations of type [`Operation::DelayNs`].
+    #[inline]
+    pub fn new_no_delay(bus: &'a M, mut cs: CS) -> Result<Self, CS::Error>
+    where
+        CS: OutputPin,
+    {
+        cs.set_high()?;
+        Ok(Self {
+            bus,
+            cs,
+            delay: super::NoDelay,
+            _bus: PhantomData,
+        })
+    }
+}
+
+impl<M, BUS, CS, D> ErrorType for HalBusMutexDevice<'_, M, BUS, CS, D>
+where
+    BUS: ErrorType,
+    CS: OutputPin,
+{
+    type Error = DeviceError<BUS::Error, CS::Error>;
+}
+
+impl<Word: Copy + 'static, M, BUS, CS, D> SpiDevice<Word> for HalBusMutexDevice<'_, M, BUS, CS, D>
+where
+    M: HalBusMutex<BUS>,
+    BUS: SpiBus<Word>,
+    CS: OutputPin,
+    D: DelayNs,
+{
+    #[inline]
+    fn transaction(&mut self, operations: &mut [Operation<'_, Word>]) -> Result<(), Self::Error> {
+        let cs = &mut self.cs;
+        let delay = &mut self.delay;
+        self.bus.lock(|bus| transaction(operations, bus, delay, cs))
+    }
+}
@@ -13,17 +13,29 @@ mod mutex;
 pub use mutex::*;
 #[cfg(any(feature = "portable-atomic", target_has_atomic = "8"))]
 mod atomic;
+#[cfg(feature = "no-atomics")]
+mod cell;
 mod critical_section;
+mod hal_bus_mutex;
+mod multi_device;
 mod shared;
 #[cfg(any(feature = "portable-atomic", target_has_atomic = "8"))]
 pub use atomic::*;
+#[cfg(feature = "no-atomics")]
+pub use cell::*;
 
 #[cfg(feature = "alloc")]
 mod rc;
 #[cfg(feature = "alloc")]
 pub use rc::*;
+#[cfg(feature = "test-utils")]
+mod recording;
+#[cfg(feature = "test-utils")]
+pub use recording::*;
 
 pub use self::critical_section::*;
+pub use hal_bus_mutex::*;
+pub use multi_device::*;
 
 #[cfg(feature = "defmt-03")]
 use crate::defmt;
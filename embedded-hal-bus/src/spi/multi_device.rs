@@ -0,0 +1,87 @@
+use embedded_hal::delay::DelayNs;
+use embedded_hal::digital::OutputPin;
+use embedded_hal::spi::{Operation, SpiBus};
+
+use super::shared::transaction;
+use super::DeviceError;
+
+/// SPI bus manager with exclusive access to the bus and the CS pins of `N` devices.
+///
+/// Unlike the [`SpiDevice`](embedded_hal::spi::SpiDevice) implementations, which each own the CS
+/// pin of a single device, `MultiDeviceSpi` manages all the CS pins centrally. Devices are
+/// addressed by their index in the CS pin array. Since `MultiDeviceSpi` has exclusive access
+/// to the bus, only one device can be selected at a time, without any locking.
+///
+/// This is useful for protocol managers enforcing constraints across devices, e.g. when an SD
+/// card, a display and a flash memory share a bus.
+pub struct MultiDeviceSpi<BUS, const N: usize, CS, D> {
+    bus: BUS,
+    cs: [CS; N],
+    delay: D,
+}
+
+impl<BUS, const N: usize, CS, D> MultiDeviceSpi<BUS, N, CS, D> {
+    /// Create a new [`MultiDeviceSpi`].
+    ///
+    /// This sets all the `cs` pins high, and returns an error if that fails. It is recommended
+    /// to set the pins high the moment they're configured as outputs, to avoid glitches.
+    #[inline]
+    pub fn new(bus: BUS, mut cs: [CS; N], delay: D) -> Result<Self, CS::Error>
+    where
+        CS: OutputPin,
+    {
+        for pin in &mut cs {
+            pin.set_high()?;
+        }
+        Ok(Self { bus, cs, delay })
+    }
+
+    /// Returns a reference to the underlying bus object.
+    #[inline]
+    pub fn bus(&self) -> &BUS {
+        &self.bus
+    }
+
+    /// Returns a mutable reference to the underlying bus object.
+    #[inline]
+    pub fn bus_mut(&mut self) -> &mut BUS {
+        &mut self.bus
+    }
+
+    /// Consumes the `MultiDeviceSpi`, returning the bus, CS pins and delay.
+    #[inline]
+    pub fn into_inner(self) -> (BUS, [CS; N], D) {
+        (self.bus, self.cs, self.delay)
+    }
+}
+
+impl<BUS, const N: usize, CS, D> MultiDeviceSpi<BUS, N, CS, D>
+where
+    CS: OutputPin,
+    D: DelayNs,
+{
+    /// Perform a transaction against the device with the given index.
+    ///
+    /// This has the same semantics as [`SpiDevice::transaction`](embedded_hal::spi::SpiDevice::transaction),
+    /// asserting the CS pin of `device` for the duration of the transaction.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `device` is not less than `N`.
+    #[inline]
+    pub fn transaction_with_device<Word: Copy + 'static>(
+        &mut self,
+        device: usize,
+        operations: &mut [Operation<'_, Word>],
+    ) -> Result<(), DeviceError<BUS::Error, CS::Error>>
+    where
+        BUS: SpiBus<Word>,
+    {
+        transaction(
+            operations,
+            &mut self.bus,
+            &mut self.delay,
+            &mut self.cs[device],
+        )
+    }
+}
@@ -14,7 +14,8 @@ use crate::spi::shared::transaction;
 ///
 /// A single [`SpiBus`] is shared via [`RefCell`], and its ownership is handled by [`Rc`].
 /// Both of these mechanisms only allow sharing within a single thread (or interrupt priority level).
-/// For this reason, this does not implement [`Send`].
+/// For this reason, this implements neither [`Send`] nor [`Sync`].
+/// For sharing a bus between threads, use `MutexDevice` (requires the `std` feature) instead.
 ///
 /// When this structure is dropped, the reference count of the `Bus` instance will be decremented,
 /// and it will be cleaned up once the reference count reaches zero.
@@ -0,0 +1,206 @@
+use embedded_hal::spi::{ErrorType, Operation, SpiDevice};
+#[cfg(feature = "async")]
+use embedded_hal_async::spi::SpiDevice as AsyncSpiDevice;
+
+/// Maximum number of operations recorded by [`RecordingDevice`], and of words recorded per buffer.
+pub const RECORDING_CAPACITY: usize = 64;
+
+/// Buffer of words recorded by [`RecordingDevice`].
+pub type RecordedWords<Word> = heapless::Vec<Word, RECORDING_CAPACITY>;
+
+/// Operation recorded by [`RecordingDevice`].
+///
+/// This mirrors [`Operation`], with copies of the buffers: `read` buffers hold the words read
+/// from the device, `write` buffers the words written to it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RecordedOp<Word> {
+    /// [`Operation::Read`], with the words read.
+    Read(RecordedWords<Word>),
+    /// [`Operation::Write`], with the words written.
+    Write(RecordedWords<Word>),
+    /// [`Operation::Transfer`].
+    Transfer {
+        /// Words read.
+        read: RecordedWords<Word>,
+        /// Words written.
+        write: RecordedWords<Word>,
+    },
+    /// [`Operation::TransferInPlace`].
+    TransferInPlace {
+        /// Words read.
+        read: RecordedWords<Word>,
+        /// Words written.
+        write: RecordedWords<Word>,
+    },
+    /// [`Operation::DelayNs`].
+    DelayNs(u32),
+}
+
+impl<Word: Copy> RecordedOp<Word> {
+    /// Records `op` before it is executed, with the words to write.
+    fn before(op: &Operation<'_, Word>) -> Self {
+        match op {
+            Operation::Read(_) => Self::Read(RecordedWords::new()),
+            Operation::Write(buf) => Self::Write(record(buf)),
+            Operation::Transfer(_, write) => Self::Transfer {
+                read: RecordedWords::new(),
+                write: record(write),
+            },
+            Operation::TransferInPlace(buf) => Self::TransferInPlace {
+                read: RecordedWords::new(),
+                write: record(buf),
+            },
+            Operation::DelayNs(ns) => Self::DelayNs(*ns),
+        }
+    }
+
+    /// Records the words read by `op`, once executed.
+    fn complete(&mut self, op: &Operation<'_, Word>) {
+        match (self, op) {
+            (Self::Read(read), Operation::Read(buf))
+            | (Self::Transfer { read, .. }, Operation::Transfer(buf, _))
+            | (Self::TransferInPlace { read, .. }, Operation::TransferInPlace(buf)) => {
+                *read = record(buf)
+            }
+            _ => {}
+        }
+    }
+}
+
+fn record<Word: Copy>(buf: &[Word]) -> RecordedWords<Word> {
+    buf.iter().take(RECORDING_CAPACITY).copied().collect()
+}
+
+/// [`SpiDevice`] wrapper recording all the operations performed, for testing.
+///
+/// This allows tests of SPI drivers to check the exact words exchanged with a device, e.g. a
+/// simulated device implementing [`SpiDevice`]. Operations are recorded whether the transaction
+/// succeeds or not, in which case the words read are unspecified.
+///
+/// Only the first [`RECORDING_CAPACITY`] operations, and the first [`RECORDING_CAPACITY`] words of
+/// each buffer, are recorded. Use [`clear_recording`](RecordingDevice::clear_recording) between
+/// steps of a test to record more.
+///
+/// ```
+/// use embedded_hal::spi::{ErrorType, Operation, SpiDevice};
+/// use embedded_hal_bus::spi::{RecordedOp, RecordingDevice};
+/// # struct FakeDevice;
+/// # impl ErrorType for FakeDevice {
+/// #     type Error = core::convert::Infallible;
+/// # }
+/// # impl SpiDevice for FakeDevice {
+/// #     fn transaction(&mut self, ops: &mut [Operation<'_, u8>]) -> Result<(), Self::Error> {
+/// #         for op in ops {
+/// #             if let Operation::Read(buf) = op {
+/// #                 buf.fill(0x42);
+/// #             }
+/// #         }
+/// #         Ok(())
+/// #     }
+/// # }
+///
+/// let mut device = RecordingDevice::new(FakeDevice);
+/// let mut id = [0; 1];
+/// device.transaction(&mut [Operation::Write(&[0x0F]), Operation::Read(&mut id)])?;
+///
+/// assert_eq!(
+///     device.recorded_ops(),
+///     [
+///         RecordedOp::Write([0x0F].into_iter().collect()),
+///         RecordedOp::Read([0x42].into_iter().collect()),
+///     ]
+/// );
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+pub struct RecordingDevice<D, Word: Copy + 'static = u8> {
+    device: D,
+    ops: heapless::Vec<RecordedOp<Word>, RECORDING_CAPACITY>,
+}
+
+impl<D, Word: Copy + 'static> RecordingDevice<D, Word> {
+    /// Create a new [`RecordingDevice`].
+    #[inline]
+    pub fn new(device: D) -> Self {
+        Self {
+            device,
+            ops: heapless::Vec::new(),
+        }
+    }
+
+    /// Returns the operations recorded since the device was created or the recording cleared.
+    #[inline]
+    pub fn recorded_ops(&self) -> &[RecordedOp<Word>] {
+        &self.ops
+    }
+
+    /// Clear the recorded operations.
+    #[inline]
+    pub fn clear_recording(&mut self) {
+        self.ops.clear();
+    }
+
+    /// Returns a reference to the underlying device.
+    #[inline]
+    pub fn inner(&self) -> &D {
+        &self.device
+    }
+
+    /// Returns a mutable reference to the underlying device.
+    #[inline]
+    pub fn inner_mut(&mut self) -> &mut D {
+        &mut self.device
+    }
+
+    /// Consumes the [`RecordingDevice`], returning the underlying device.
+    #[inline]
+    pub fn into_inner(self) -> D {
+        self.device
+    }
+
+    /// Records the operations before they are executed, returning the index of the first one.
+    fn record_before(&mut self, operations: &[Operation<'_, Word>]) -> usize {
+        let start = self.ops.len();
+        for op in operations {
+            if self.ops.push(RecordedOp::before(op)).is_err() {
+                break;
+            }
+        }
+        start
+    }
+
+    /// Records the words read by the operations, once executed.
+    fn record_after(&mut self, start: usize, operations: &[Operation<'_, Word>]) {
+        for (recorded, op) in self.ops[start..].iter_mut().zip(operations) {
+            recorded.complete(op);
+        }
+    }
+}
+
+impl<D: ErrorType, Word: Copy + 'static> ErrorType for RecordingDevice<D, Word> {
+    type Error = D::Error;
+}
+
+impl<D: SpiDevice<Word>, Word: Copy + 'static> SpiDevice<Word> for RecordingDevice<D, Word> {
+    fn transaction(&mut self, operations: &mut [Operation<'_, Word>]) -> Result<(), Self::Error> {
+        let start = self.record_before(operations);
+        let res = self.device.transaction(operations);
+        self.record_after(start, operations);
+        res
+    }
+}
+
+#[cfg(feature = "async")]
+#[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+impl<D: AsyncSpiDevice<Word>, Word: Copy + 'static> AsyncSpiDevice<Word>
+    for RecordingDevice<D, Word>
+{
+    async fn transaction(
+        &mut self,
+        operations: &mut [Operation<'_, Word>],
+    ) -> Result<(), Self::Error> {
+        let start = self.record_before(operations);
+        let res = self.device.transaction(operations).await;
+        self.record_after(start, operations);
+        res
+    }
+}
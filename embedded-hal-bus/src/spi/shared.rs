@@ -25,6 +25,7 @@ where
         Operation::Write(buf) => bus.write(buf),
         Operation::Transfer(read, write) => bus.transfer(read, write),
         Operation::TransferInPlace(buf) => bus.transfer_in_place(buf),
+        Operation::DelayNs(0) => Ok(()),
         Operation::DelayNs(ns) => {
             bus.flush()?;
             delay.delay_ns(*ns);
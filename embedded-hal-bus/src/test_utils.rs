@@ -0,0 +1,145 @@
+//! Utilities for developing and debugging embedded-hal applications.
+//!
+//! These are meant to help a human bring up hardware, not to be used in production code.
+
+use embedded_hal::digital::{self, OutputPin};
+use embedded_hal::i2c::I2c;
+use embedded_hal::spi::SpiBus;
+
+mod known_addresses;
+
+/// Words sent to probe a device: a JEDEC "Read Identification" command followed by dummy bytes.
+const PROBE: [u8; 4] = [0x9F, 0x00, 0x00, 0x00];
+
+/// Probes which SPI devices are electrically present on a bus.
+///
+/// For each pin in `cs_pins`, this asserts the pin, transfers a few bytes (a JEDEC "Read
+/// Identification" command, `0x9F`, followed by dummy bytes) and deasserts it. A device is
+/// considered present if any byte received on MISO is neither `0x00` nor `0xFF`, as a floating or
+/// pulled MISO line reads as all zeros or all ones. All CS pins are set high first.
+///
+/// Returns the indices in `cs_pins` of the devices that answered, up to 8 of them. Errors on the
+/// bus or on a CS pin are treated as no answer.
+///
+/// # Reliability
+///
+/// **The result of this function can't be trusted**, it is only a debugging aid:
+///
+/// - False negatives are expected: many devices ignore unknown commands and leave MISO
+///   floating or drive it to a constant level, or need a different SPI mode or frequency than
+///   the one the bus is configured for.
+/// - False positives are expected too: a floating MISO line can pick up noise, and devices may
+///   misinterpret the probe, possibly changing their state. Don't run this on a bus with
+///   devices whose state matters, such as memories in the middle of a write.
+pub fn spi_bus_probe<S, E>(
+    spi: &mut S,
+    cs_pins: &mut [&mut dyn OutputPin<Error = E>],
+) -> heapless::Vec<usize, 8>
+where
+    S: SpiBus,
+    E: digital::Error,
+{
+    let mut found = heapless::Vec::new();
+
+    for cs in cs_pins.iter_mut() {
+        let _ = cs.set_high();
+    }
+
+    for (index, cs) in cs_pins.iter_mut().enumerate() {
+        if cs.set_low().is_err() {
+            let _ = cs.set_high();
+            continue;
+        }
+
+        let mut words = PROBE;
+        let result = spi.transfer_in_place(&mut words).and_then(|_| spi.flush());
+        let _ = cs.set_high();
+
+        if result.is_ok()
+            && words.iter().any(|&w| w != 0x00 && w != 0xFF)
+            && found.push(index).is_err()
+        {
+            break;
+        }
+    }
+
+    found
+}
+
+/// Registry mapping I2C addresses to the names of devices commonly found at them.
+///
+/// The [`Default`] registry lists common sensors, displays, memories and I/O expanders. A custom
+/// table can be used to describe the devices of a specific board:
+///
+/// ```
+/// use embedded_hal_bus::test_utils::KnownDevices;
+///
+/// const BOARD: &[(u8, &str)] = &[(0x18, "LIS3DH accelerometer"), (0x50, "board ID EEPROM")];
+///
+/// let known = KnownDevices::new(BOARD);
+/// assert_eq!(known.describe(0x50), Some("board ID EEPROM"));
+/// assert_eq!(KnownDevices::default().describe(0x76), Some("BME280/BMP280"));
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct KnownDevices<'a> {
+    table: &'a [(u8, &'static str)],
+}
+
+impl<'a> KnownDevices<'a> {
+    /// Create a registry from a table of 7-bit addresses and device names.
+    #[inline]
+    pub const fn new(table: &'a [(u8, &'static str)]) -> Self {
+        Self { table }
+    }
+
+    /// Returns the names of the devices commonly found at `address`, if known.
+    pub fn describe(&self, address: u8) -> Option<&'static str> {
+        self.table
+            .iter()
+            .find(|(addr, _)| *addr == address)
+            .map(|(_, name)| *name)
+    }
+}
+
+impl Default for KnownDevices<'static> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(known_addresses::KNOWN_ADDRESSES)
+    }
+}
+
+/// Scans an I2C bus for devices.
+///
+/// This reads one byte from each non-reserved 7-bit address (`0x08..=0x77`), and returns the
+/// addresses which acknowledged. Errors other than a missing acknowledge are treated as no
+/// answer too.
+///
+/// # Reliability
+///
+/// Like [`spi_bus_probe`], this is only a debugging aid: a few devices don't answer reads
+/// outside of a proper transaction, or change state when read. Don't run it on a bus with
+/// devices whose state matters.
+pub fn i2c_bus_scan<I: I2c>(i2c: &mut I) -> heapless::Vec<u8, 128> {
+    let mut found = heapless::Vec::new();
+    for address in 0x08..=0x77 {
+        if i2c.read(address, &mut [0]).is_ok() {
+            // Can't overflow: there are fewer addresses than the capacity.
+            let _ = found.push(address);
+        }
+    }
+    found
+}
+
+/// Scans an I2C bus for devices, and describes them using `known`.
+///
+/// See [`i2c_bus_scan`] for how devices are found. Each address found is returned with the
+/// matching description from `known`, if any.
+pub fn scan_with_descriptions<I: I2c>(
+    i2c: &mut I,
+    known: &KnownDevices<'_>,
+) -> heapless::Vec<(u8, Option<&'static str>), 128> {
+    i2c_bus_scan(i2c)
+        .into_iter()
+        .map(|address| (address, known.describe(address)))
+        .collect()
+}
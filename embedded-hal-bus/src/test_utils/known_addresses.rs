@@ -0,0 +1,30 @@
+//! Well-known I2C device addresses, used by [`KnownDevices::default`](super::KnownDevices).
+//!
+//! Each entry lists devices commonly found at this 7-bit address, most of them on breakout
+//! boards. Many devices have configurable addresses: only the usual ones are listed.
+
+pub(super) const KNOWN_ADDRESSES: &[(u8, &str)] = &[
+    (0x1D, "ADXL345"),
+    (0x1E, "HMC5883L"),
+    (0x20, "PCF8574/MCP23017"),
+    (0x23, "BH1750"),
+    (0x27, "PCF8574 (LCD backpack)"),
+    (0x29, "VL53L0X/TSL2591"),
+    (0x38, "AHT20/FT6206"),
+    (0x39, "TSL2561/APDS-9960"),
+    (0x3C, "SSD1306/SH1106"),
+    (0x3D, "SSD1306"),
+    (0x40, "INA219/Si7021/PCA9685"),
+    (0x44, "SHT31/SHT40"),
+    (0x48, "TMP102/ADS1115/PCF8591"),
+    (0x50, "AT24Cxx EEPROM"),
+    (0x51, "PCF8563"),
+    (0x53, "ADXL345"),
+    (0x57, "MAX30102/DS3231 EEPROM"),
+    (0x5A, "MLX90614/CCS811"),
+    (0x60, "MCP4725/Si5351"),
+    (0x68, "MPU-6050/DS1307/DS3231"),
+    (0x69, "MPU-6050"),
+    (0x76, "BME280/BMP280"),
+    (0x77, "BME280/BMP280/BMP180"),
+];
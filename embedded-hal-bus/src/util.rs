@@ -1,5 +1,8 @@
 //! Utilities shared by all bus types.
 
+#[allow(unused_imports)]
+use core::cell::Cell;
+use core::cell::RefCell;
 #[allow(unused_imports)]
 use core::cell::UnsafeCell;
 
@@ -32,3 +35,66 @@ impl<BUS> AtomicCell<BUS> {
         }
     }
 }
+
+#[cfg(feature = "no-atomics")]
+/// Cell type used by [`spi::CellDevice`](crate::spi::CellDevice).
+///
+/// To use `CellDevice`, you must wrap the bus with this struct, and then
+/// construct multiple `CellDevice` instances with references to it.
+pub struct BusyCell<BUS> {
+    pub(crate) bus: UnsafeCell<BUS>,
+    pub(crate) busy: Cell<bool>,
+}
+
+#[cfg(feature = "no-atomics")]
+impl<BUS> BusyCell<BUS> {
+    /// Create a new `BusyCell`
+    pub fn new(bus: BUS) -> Self {
+        Self {
+            bus: UnsafeCell::new(bus),
+            busy: Cell::new(false),
+        }
+    }
+}
+
+/// Mutex abstraction used by [`spi::HalBusMutexDevice`](crate::spi::HalBusMutexDevice) and
+/// [`i2c::HalBusMutexDevice`](crate::i2c::HalBusMutexDevice).
+///
+/// Implement this trait for your own mutex type (RTOS mutex, etc.) to share a bus with it,
+/// without having to write new `SpiDevice` or `I2c` implementations.
+///
+/// This crate implements it for [`RefCell`], `critical_section::Mutex<RefCell<T>>` and,
+/// with the `std` feature, `std::sync::Mutex`.
+pub trait HalBusMutex<T> {
+    /// Lock the mutex, and call `f` with exclusive access to the inner value.
+    ///
+    /// Implementations must ensure that no other call to `lock` on the same mutex
+    /// can access the inner value until `f` returns.
+    fn lock<R, F: FnOnce(&mut T) -> R>(&self, f: F) -> R;
+}
+
+/// Panics if the `RefCell` is already borrowed.
+impl<T> HalBusMutex<T> for RefCell<T> {
+    #[inline]
+    fn lock<R, F: FnOnce(&mut T) -> R>(&self, f: F) -> R {
+        f(&mut self.borrow_mut())
+    }
+}
+
+/// A critical section is taken for the entire duration of `f`.
+impl<T> HalBusMutex<T> for critical_section::Mutex<RefCell<T>> {
+    #[inline]
+    fn lock<R, F: FnOnce(&mut T) -> R>(&self, f: F) -> R {
+        critical_section::with(|cs| f(&mut self.borrow_ref_mut(cs)))
+    }
+}
+
+/// Panics if the `Mutex` is poisoned.
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl<T> HalBusMutex<T> for std::sync::Mutex<T> {
+    #[inline]
+    fn lock<R, F: FnOnce(&mut T) -> R>(&self, f: F) -> R {
+        f(&mut self.lock().unwrap())
+    }
+}
@@ -1,4 +1,8 @@
 //! Serial interface.
+//!
+//! To use a serial port implementing [`Read<u8>`] and [`Write<u8>`] with code written against the
+//! `embedded-io` traits, see `BlockingNbRead` and `BlockingNbWrite` in the `embedded-io-adapters`
+//! crate, behind its `embedded-hal-nb-1` feature.
 
 /// Serial error.
 pub trait Error: core::fmt::Debug {
@@ -34,6 +38,9 @@ pub enum ErrorKind {
     Parity,
     /// Serial line is too noisy to read valid data.
     Noise,
+    /// A configuration parameter was invalid, e.g. a baud rate that cannot be achieved
+    /// with the peripheral clock.
+    InvalidInput,
     /// A different error occurred. The original error may contain more information.
     Other,
 }
@@ -54,6 +61,7 @@ impl core::fmt::Display for ErrorKind {
             Self::Overrun => write!(f, "The peripheral receive buffer was overrun"),
             Self::Parity => write!(f, "Parity check failed"),
             Self::Noise => write!(f, "Serial line is too noisy to read valid data"),
+            Self::InvalidInput => write!(f, "A configuration parameter was invalid"),
             Self::FrameFormat => write!(
                 f,
                 "Received data does not conform to the peripheral configuration"
@@ -115,6 +123,339 @@ impl<T: Write<Word> + ?Sized, Word: Copy> Write<Word> for &mut T {
     }
 }
 
+/// Serial interface whose baud rate can be changed at runtime.
+///
+/// This is useful for protocols that negotiate the baud rate after the connection has been
+/// established, such as LIN auto-baud detection or serial bootloader handshakes.
+pub trait ReconfigurableSerial: ErrorType {
+    /// Sets the baud rate of the serial interface, in bits per second.
+    ///
+    /// Implementations must flush any pending data before reconfiguring, blocking if needed,
+    /// so that no words are sent with the wrong baud rate.
+    ///
+    /// The baud rate actually configured may differ slightly from `baud` due to clock divisor
+    /// quantization, see [`actual_baud_rate`](ReconfigurableSerial::actual_baud_rate).
+    /// If `baud` can't be achieved within the tolerance of the implementation (typically ±2%),
+    /// an error of kind [`ErrorKind::InvalidInput`] is returned and the configuration is left unchanged.
+    fn set_baud_rate(&mut self, baud: u32) -> Result<(), Self::Error>;
+
+    /// Returns the baud rate actually configured, in bits per second.
+    fn actual_baud_rate(&mut self) -> Result<u32, Self::Error>;
+}
+
+impl<T: ReconfigurableSerial + ?Sized> ReconfigurableSerial for &mut T {
+    #[inline]
+    fn set_baud_rate(&mut self, baud: u32) -> Result<(), Self::Error> {
+        T::set_baud_rate(self, baud)
+    }
+
+    #[inline]
+    fn actual_baud_rate(&mut self) -> Result<u32, Self::Error> {
+        T::actual_baud_rate(self)
+    }
+}
+
+/// RS-232 modem control lines.
+///
+/// All methods are defined in terms of the logical state of the lines: `true` means the line is
+/// asserted. The electrical polarity of an asserted line, at the UART pins or on the connector, is
+/// implementation-defined.
+pub trait ModemLines: ErrorType {
+    /// Asserts the DTR (Data Terminal Ready) output if `asserted` is `true`, deasserts it otherwise.
+    fn set_dtr(&mut self, asserted: bool) -> Result<(), Self::Error>;
+
+    /// Returns `true` if the DSR (Data Set Ready) input is asserted.
+    fn dsr(&mut self) -> Result<bool, Self::Error>;
+
+    /// Returns `true` if the RI (Ring Indicator) input is asserted.
+    fn ri(&mut self) -> Result<bool, Self::Error>;
+
+    /// Returns `true` if the DCD (Data Carrier Detect) input is asserted.
+    fn dcd(&mut self) -> Result<bool, Self::Error>;
+
+    /// Asserts the RTS (Request To Send) output if `asserted` is `true`, deasserts it otherwise.
+    ///
+    /// If hardware flow control is enabled, the implementation may be driving RTS itself,
+    /// in which case this may have no effect.
+    fn set_rts(&mut self, asserted: bool) -> Result<(), Self::Error>;
+
+    /// Returns `true` if the CTS (Clear To Send) input is asserted.
+    fn cts(&mut self) -> Result<bool, Self::Error>;
+}
+
+impl<T: ModemLines + ?Sized> ModemLines for &mut T {
+    #[inline]
+    fn set_dtr(&mut self, asserted: bool) -> Result<(), Self::Error> {
+        T::set_dtr(self, asserted)
+    }
+
+    #[inline]
+    fn dsr(&mut self) -> Result<bool, Self::Error> {
+        T::dsr(self)
+    }
+
+    #[inline]
+    fn ri(&mut self) -> Result<bool, Self::Error> {
+        T::ri(self)
+    }
+
+    #[inline]
+    fn dcd(&mut self) -> Result<bool, Self::Error> {
+        T::dcd(self)
+    }
+
+    #[inline]
+    fn set_rts(&mut self, asserted: bool) -> Result<(), Self::Error> {
+        T::set_rts(self, asserted)
+    }
+
+    #[inline]
+    fn cts(&mut self) -> Result<bool, Self::Error> {
+        T::cts(self)
+    }
+}
+
+/// Break condition transmission.
+///
+/// A break condition holds the transmit line low for longer than a frame. It is used by the
+/// LIN bus to mark the start of a frame, and by some bootloader protocols.
+pub trait SendBreak: ErrorType {
+    /// Sends a break condition lasting `duration_bit_times` bit times.
+    ///
+    /// Implementations flush any pending data first, and block until the break has been sent.
+    /// Hardware that can only send fixed-length breaks uses the closest supported length that is
+    /// not shorter than `duration_bit_times`. A LIN break is at least 13 bit times long.
+    fn send_break(&mut self, duration_bit_times: u8) -> Result<(), Self::Error>;
+}
+
+impl<T: SendBreak + ?Sized> SendBreak for &mut T {
+    #[inline]
+    fn send_break(&mut self, duration_bit_times: u8) -> Result<(), Self::Error> {
+        T::send_break(self, duration_bit_times)
+    }
+}
+
+/// Break condition detection.
+///
+/// This is the receiver side of [`SendBreak`].
+pub trait BreakDetect: ErrorType {
+    /// Returns `true` if a break condition has been detected since the flag was last cleared.
+    fn is_break_detected(&mut self) -> Result<bool, Self::Error>;
+
+    /// Clears the break detection flag.
+    fn clear_break(&mut self) -> Result<(), Self::Error>;
+}
+
+impl<T: BreakDetect + ?Sized> BreakDetect for &mut T {
+    #[inline]
+    fn is_break_detected(&mut self) -> Result<bool, Self::Error> {
+        T::is_break_detected(self)
+    }
+
+    #[inline]
+    fn clear_break(&mut self) -> Result<(), Self::Error> {
+        T::clear_break(self)
+    }
+}
+
+/// Idle line detection.
+///
+/// UART peripherals can usually detect when the receive line has been idle for one frame time
+/// after receiving data, and raise an interrupt. This is used to receive variable-length packets
+/// reliably, the idle line marking the end of a packet.
+pub trait IdleLineDetect: ErrorType {
+    /// Enables the idle line interrupt.
+    fn enable_idle_interrupt(&mut self) -> Result<(), Self::Error>;
+
+    /// Disables the idle line interrupt.
+    fn disable_idle_interrupt(&mut self) -> Result<(), Self::Error>;
+
+    /// Returns `true` if an idle line has been detected since the last call.
+    ///
+    /// Implementations clear the idle flag when this returns `true`.
+    fn is_idle(&mut self) -> Result<bool, Self::Error>;
+}
+
+impl<T: IdleLineDetect + ?Sized> IdleLineDetect for &mut T {
+    #[inline]
+    fn enable_idle_interrupt(&mut self) -> Result<(), Self::Error> {
+        T::enable_idle_interrupt(self)
+    }
+
+    #[inline]
+    fn disable_idle_interrupt(&mut self) -> Result<(), Self::Error> {
+        T::disable_idle_interrupt(self)
+    }
+
+    #[inline]
+    fn is_idle(&mut self) -> Result<bool, Self::Error> {
+        T::is_idle(self)
+    }
+}
+
+/// Size of the receive queue of [`XonXoffSerial`].
+const XON_XOFF_RX_QUEUE_LEN: usize = 8;
+
+/// Serial port wrapper implementing XON/XOFF software flow control.
+///
+/// The wrapper watches the received bytes for [`XOFF`](Self::XOFF), and stops transmitting until
+/// [`XON`](Self::XON) is received. While transmission is paused, [`write`](Write::write) returns
+/// [`nb::Error::WouldBlock`], so the caller keeps the word until the peer is ready again.
+///
+/// Received bytes are only seen when the wrapper reads them: [`write`](Write::write) reads all the
+/// bytes available before transmitting, keeping data bytes in a queue of
+/// [`RX_QUEUE_LEN`](Self::RX_QUEUE_LEN) bytes for the next [`read`](Read::read). While the queue
+/// is full, incoming XON and XOFF can't be detected, so the application must keep reading.
+///
+/// By default, XON and XOFF are consumed by the wrapper. They can be forwarded to the application
+/// too with [`set_forward_control`](Self::set_forward_control), for protocols which need to see them.
+#[derive(Clone, Debug)]
+pub struct XonXoffSerial<S> {
+    serial: S,
+    paused: bool,
+    forward_control: bool,
+    queue: [u8; XON_XOFF_RX_QUEUE_LEN],
+    queue_start: usize,
+    queue_len: usize,
+}
+
+impl<S> XonXoffSerial<S> {
+    /// XON control byte (DC1), resuming transmission.
+    pub const XON: u8 = 0x11;
+    /// XOFF control byte (DC3), pausing transmission.
+    pub const XOFF: u8 = 0x13;
+    /// Number of received bytes kept by [`write`](Write::write) for the next
+    /// [`read`](Read::read).
+    pub const RX_QUEUE_LEN: usize = XON_XOFF_RX_QUEUE_LEN;
+
+    /// Create a new wrapper, with transmission enabled.
+    #[inline]
+    pub fn new(serial: S) -> Self {
+        Self {
+            serial,
+            paused: false,
+            forward_control: false,
+            queue: [0; XON_XOFF_RX_QUEUE_LEN],
+            queue_start: 0,
+            queue_len: 0,
+        }
+    }
+
+    /// Set whether received XON and XOFF bytes are returned by [`read`](Read::read), in addition
+    /// to controlling the transmission.
+    #[inline]
+    pub fn set_forward_control(&mut self, forward: bool) {
+        self.forward_control = forward;
+    }
+
+    /// Returns `true` if transmission is paused by the peer.
+    #[inline]
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Consume the wrapper, returning the inner serial port.
+    ///
+    /// Received bytes not yet returned by [`read`](Read::read) are lost.
+    #[inline]
+    pub fn into_inner(self) -> S {
+        self.serial
+    }
+
+    fn push(&mut self, word: u8) {
+        self.queue[(self.queue_start + self.queue_len) % XON_XOFF_RX_QUEUE_LEN] = word;
+        self.queue_len += 1;
+    }
+
+    fn pop(&mut self) -> Option<u8> {
+        if self.queue_len == 0 {
+            return None;
+        }
+        let word = self.queue[self.queue_start];
+        self.queue_start = (self.queue_start + 1) % XON_XOFF_RX_QUEUE_LEN;
+        self.queue_len -= 1;
+        Some(word)
+    }
+}
+
+impl<S: Read<u8>> XonXoffSerial<S> {
+    /// Read a byte from the serial port, handling control bytes.
+    ///
+    /// Returns `Ok(None)` if a control byte was consumed.
+    fn receive(&mut self) -> nb::Result<Option<u8>, S::Error> {
+        let word = self.serial.read()?;
+        match word {
+            Self::XON => self.paused = false,
+            Self::XOFF => self.paused = true,
+            _ => return Ok(Some(word)),
+        }
+        Ok(self.forward_control.then_some(word))
+    }
+
+    /// Read all the available bytes, until the queue is full.
+    fn poll_receive(&mut self) -> Result<(), S::Error> {
+        while self.queue_len < XON_XOFF_RX_QUEUE_LEN {
+            match self.receive() {
+                Ok(Some(word)) => self.push(word),
+                Ok(None) => {}
+                Err(nb::Error::WouldBlock) => break,
+                Err(nb::Error::Other(e)) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<S: Write<u8>> XonXoffSerial<S> {
+    /// Ask the peer to pause its transmission, by sending XOFF.
+    ///
+    /// XOFF is sent even if the transmission is paused by the peer.
+    #[inline]
+    pub fn send_xoff(&mut self) -> Result<(), S::Error> {
+        nb::block!(self.serial.write(Self::XOFF))
+    }
+
+    /// Ask the peer to resume its transmission, by sending XON.
+    ///
+    /// XON is sent even if the transmission is paused by the peer.
+    #[inline]
+    pub fn send_xon(&mut self) -> Result<(), S::Error> {
+        nb::block!(self.serial.write(Self::XON))
+    }
+}
+
+impl<S: ErrorType> ErrorType for XonXoffSerial<S> {
+    type Error = S::Error;
+}
+
+impl<S: Read<u8>> Read<u8> for XonXoffSerial<S> {
+    fn read(&mut self) -> nb::Result<u8, Self::Error> {
+        if let Some(word) = self.pop() {
+            return Ok(word);
+        }
+        loop {
+            if let Some(word) = self.receive()? {
+                return Ok(word);
+            }
+        }
+    }
+}
+
+impl<S: Read<u8> + Write<u8>> Write<u8> for XonXoffSerial<S> {
+    fn write(&mut self, word: u8) -> nb::Result<(), Self::Error> {
+        self.poll_receive()?;
+        if self.paused {
+            return Err(nb::Error::WouldBlock);
+        }
+        self.serial.write(word)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> nb::Result<(), Self::Error> {
+        self.serial.flush()
+    }
+}
+
 /// Implementation of `core::fmt::Write` for the HAL's `serial::Write`.
 ///
 /// TODO write example of usage
@@ -131,3 +472,140 @@ where
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Error of [`MockSerial`].
+    #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Overrun
+        }
+    }
+
+    /// Serial port returning scripted read results, and recording the written words.
+    struct MockSerial {
+        rx: [nb::Result<u8, MockError>; 16],
+        rx_pos: usize,
+        rx_len: usize,
+        tx: [u8; 16],
+        tx_len: usize,
+    }
+
+    impl MockSerial {
+        fn new(rx: &[nb::Result<u8, MockError>]) -> Self {
+            let mut mock = Self {
+                rx: [Err(nb::Error::WouldBlock); 16],
+                rx_pos: 0,
+                rx_len: rx.len(),
+                tx: [0; 16],
+                tx_len: 0,
+            };
+            mock.rx[..rx.len()].copy_from_slice(rx);
+            mock
+        }
+
+        fn written(&self) -> &[u8] {
+            &self.tx[..self.tx_len]
+        }
+    }
+
+    impl ErrorType for MockSerial {
+        type Error = MockError;
+    }
+
+    impl Read<u8> for MockSerial {
+        fn read(&mut self) -> nb::Result<u8, MockError> {
+            if self.rx_pos == self.rx_len {
+                return Err(nb::Error::WouldBlock);
+            }
+            self.rx_pos += 1;
+            self.rx[self.rx_pos - 1]
+        }
+    }
+
+    impl Write<u8> for MockSerial {
+        fn write(&mut self, word: u8) -> nb::Result<(), MockError> {
+            self.tx[self.tx_len] = word;
+            self.tx_len += 1;
+            Ok(())
+        }
+
+        fn flush(&mut self) -> nb::Result<(), MockError> {
+            Ok(())
+        }
+    }
+
+    type Xs = XonXoffSerial<MockSerial>;
+
+    #[test]
+    fn xoff_data_xon() {
+        let mut serial = Xs::new(MockSerial::new(&[Ok(Xs::XOFF), Ok(b'a'), Ok(Xs::XON)]));
+        // XOFF and XON are both seen before transmitting, even with a data byte in between.
+        serial.write(b'x').unwrap();
+        assert!(!serial.is_paused());
+        assert_eq!(serial.read(), Ok(b'a'));
+        assert_eq!(serial.read(), Err(nb::Error::WouldBlock));
+        assert_eq!(serial.into_inner().written(), b"x");
+    }
+
+    #[test]
+    fn paused_until_xon() {
+        let mut serial = Xs::new(MockSerial::new(&[Ok(Xs::XOFF), Ok(b'a')]));
+        assert_eq!(serial.write(b'x'), Err(nb::Error::WouldBlock));
+        assert!(serial.is_paused());
+
+        // XON arrives while a data byte is still queued.
+        serial.serial.rx[2] = Ok(Xs::XON);
+        serial.serial.rx_len = 3;
+        serial.write(b'x').unwrap();
+        assert_eq!(serial.read(), Ok(b'a'));
+        assert_eq!(serial.into_inner().written(), b"x");
+    }
+
+    #[test]
+    fn forward_control() {
+        let mut serial = Xs::new(MockSerial::new(&[Ok(Xs::XOFF), Ok(b'a'), Ok(Xs::XON)]));
+        serial.set_forward_control(true);
+        assert_eq!(serial.read(), Ok(Xs::XOFF));
+        assert!(serial.is_paused());
+        assert_eq!(serial.read(), Ok(b'a'));
+        assert_eq!(serial.read(), Ok(Xs::XON));
+        assert!(!serial.is_paused());
+    }
+
+    #[test]
+    fn errors_pass_through() {
+        let mut serial = Xs::new(MockSerial::new(&[
+            Ok(b'a'),
+            Err(nb::Error::Other(MockError)),
+            Err(nb::Error::Other(MockError)),
+            Ok(b'b'),
+        ]));
+        // A receive error is returned by `write`, keeping the bytes received before it.
+        assert_eq!(serial.write(b'x'), Err(nb::Error::Other(MockError)));
+        assert_eq!(serial.read(), Ok(b'a'));
+        assert_eq!(serial.read(), Err(nb::Error::Other(MockError)));
+        assert_eq!(serial.read(), Ok(b'b'));
+        serial.write(b'x').unwrap();
+        assert_eq!(serial.into_inner().written(), b"x");
+    }
+
+    #[test]
+    fn full_queue() {
+        let mut rx = [Ok(0); 10];
+        for (i, word) in rx.iter_mut().enumerate() {
+            *word = Ok(b'0' + i as u8);
+        }
+        let mut serial = Xs::new(MockSerial::new(&rx));
+        serial.write(b'x').unwrap();
+        for i in 0..10 {
+            assert_eq!(serial.read(), Ok(b'0' + i));
+        }
+        assert_eq!(serial.read(), Err(nb::Error::WouldBlock));
+    }
+}
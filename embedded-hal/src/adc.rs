@@ -0,0 +1,597 @@
+//! Analog-to-digital converter traits.
+
+#[cfg(feature = "defmt-03")]
+use crate::defmt;
+
+/// Error
+pub trait Error: core::fmt::Debug {
+    /// Convert error to a generic error kind.
+    ///
+    /// By using this method, errors freely defined by HAL implementations
+    /// can be converted to a set of generic errors upon which generic
+    /// code can act.
+    fn kind(&self) -> ErrorKind;
+}
+
+impl Error for core::convert::Infallible {
+    #[inline]
+    fn kind(&self) -> ErrorKind {
+        match *self {}
+    }
+}
+
+/// Error kind.
+///
+/// This represents a common set of operation errors. HAL implementations are
+/// free to define more specific or additional error types. However, by providing
+/// a mapping to these common errors, generic code can still react to them.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// The requested channel or configuration is not supported by the hardware.
+    InvalidInput,
+    /// A different error occurred. The original error may contain more information.
+    Other,
+}
+
+impl Error for ErrorKind {
+    #[inline]
+    fn kind(&self) -> ErrorKind {
+        *self
+    }
+}
+
+impl core::error::Error for ErrorKind {}
+
+impl core::fmt::Display for ErrorKind {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::InvalidInput => write!(
+                f,
+                "The requested channel or configuration is not supported by the hardware"
+            ),
+            Self::Other => write!(
+                f,
+                "A different error occurred. The original error may contain more information"
+            ),
+        }
+    }
+}
+
+/// Error type trait.
+///
+/// This just defines the error type, to be used by the other traits.
+pub trait ErrorType {
+    /// Error type
+    type Error: Error;
+}
+
+impl<T: ErrorType + ?Sized> ErrorType for &mut T {
+    type Error = T::Error;
+}
+
+/// Read data from an ADC.
+///
+/// # Note for Implementers
+///
+/// This should wait until data is ready and then read it.
+///
+/// # Examples
+///
+/// In the first naive example, [`Voltmeter`] is implemented
+/// using a spin loop and only returns once data is ready.
+///
+/// ```
+/// # use embedded_hal::adc::{ErrorKind, ErrorType, Error, Voltmeter};
+/// #
+/// struct MySpinningAdc;
+///
+/// impl MySpinningAdc {
+///     pub fn is_ready(&mut self) -> bool {
+///         // Just pretend this returns `false` the first few times.
+///         true
+///     }
+///
+///     pub fn data(&mut self) -> i64 {
+///         3300
+///     }
+/// }
+///
+/// impl ErrorType for MySpinningAdc {
+///     type Error = ErrorKind;
+/// }
+///
+/// impl Voltmeter for MySpinningAdc {
+///     fn measure_nv(&mut self) -> Result<i64, Self::Error> {
+///         while !self.is_ready() {
+///             core::hint::spin_loop();
+///         }
+///
+///         Ok(self.data() * 1_000_000)
+///     }
+/// }
+/// ```
+pub trait Voltmeter: ErrorType {
+    /// Measures voltage in nV (nanovolts).
+    ///
+    /// This can measure between -9223372036.854775808V and 9223372036.854775807V.
+    fn measure_nv(&mut self) -> Result<i64, Self::Error>;
+
+    /// Measures voltage in µV (microvolts).
+    ///
+    /// This can measure between -2147.483648V and 2147.483647V.
+    /// If you need to measure a larger range, use [`measure_nv`](Voltmeter::measure_nv) instead.
+    ///
+    /// When overriding the default implementation, ensure that the measured voltage is clamped
+    /// between [`i32::MIN`] and [`i32::MAX`].
+    #[inline]
+    fn measure_uv(&mut self) -> Result<i32, Self::Error> {
+        Ok((self.measure_nv()? / 1_000).clamp(i32::MIN.into(), i32::MAX.into()) as i32)
+    }
+
+    /// Measures voltage in mV (millivolts).
+    ///
+    /// This can measure between -32.768V and 32.767V.
+    /// If you need to measure a larger range,
+    /// use [`measure_uv`](Voltmeter::measure_uv) or [`measure_nv`](Voltmeter::measure_nv) instead.
+    ///
+    /// When overriding the default implementation, ensure that the measured voltage is clamped
+    /// between [`i16::MIN`] and [`i16::MAX`].
+    #[inline]
+    fn measure_mv(&mut self) -> Result<i16, Self::Error> {
+        Ok((self.measure_uv()? / 1_000).clamp(i16::MIN.into(), i16::MAX.into()) as i16)
+    }
+}
+
+impl<T: Voltmeter + ?Sized> Voltmeter for &mut T {
+    #[inline]
+    fn measure_nv(&mut self) -> Result<i64, Self::Error> {
+        T::measure_nv(self)
+    }
+
+    #[inline]
+    fn measure_uv(&mut self) -> Result<i32, Self::Error> {
+        T::measure_uv(self)
+    }
+
+    #[inline]
+    fn measure_mv(&mut self) -> Result<i16, Self::Error> {
+        T::measure_mv(self)
+    }
+}
+
+/// Read normalized samples from an ADC.
+///
+/// Samples are returned as a fraction of the reference voltage, in the range `0.0..=1.0`.
+/// This is convenient for application code working with normalized values, such as DSP or
+/// interpolation.
+///
+/// This trait is only available with the `float` Cargo feature. On targets without a
+/// hardware FPU, the floating-point conversion is done in software, which is significantly
+/// slower and increases code size.
+#[cfg(feature = "float")]
+#[cfg_attr(docsrs, doc(cfg(feature = "float")))]
+pub trait NormalizedAdc: Voltmeter {
+    /// Returns the reference voltage in nV (nanovolts).
+    ///
+    /// This is the voltage corresponding to a normalized sample of `1.0`.
+    fn reference_nv(&self) -> i64;
+
+    /// Measures a sample normalized to the reference voltage.
+    ///
+    /// The result is clamped to `0.0..=1.0`.
+    #[inline]
+    fn measure_normalized(&mut self) -> Result<f32, Self::Error> {
+        let nv = self.measure_nv()?;
+        let reference_nv = self.reference_nv();
+        if reference_nv <= 0 {
+            return Ok(0.0);
+        }
+        Ok((nv as f32 / reference_nv as f32).clamp(0.0, 1.0))
+    }
+}
+
+#[cfg(feature = "float")]
+impl<T: NormalizedAdc + ?Sized> NormalizedAdc for &mut T {
+    #[inline]
+    fn reference_nv(&self) -> i64 {
+        T::reference_nv(self)
+    }
+
+    #[inline]
+    fn measure_normalized(&mut self) -> Result<f32, Self::Error> {
+        T::measure_normalized(self)
+    }
+}
+
+/// Read the voltage difference between two ADC inputs.
+///
+/// Channel indices are HAL-defined. The measured value is the voltage on `positive_channel`
+/// minus the voltage on `negative_channel`, so it is negative if the negative input is at
+/// a higher voltage than the positive input.
+///
+/// Not every hardware configuration supports every channel pair. Implementations
+/// return an error of kind [`ErrorKind::InvalidInput`] for unsupported pairs.
+pub trait DifferentialVoltmeter: ErrorType {
+    /// Measures the voltage difference in nV (nanovolts).
+    fn measure_diff_nv(
+        &mut self,
+        positive_channel: u8,
+        negative_channel: u8,
+    ) -> Result<i64, Self::Error>;
+
+    /// Measures the voltage difference in µV (microvolts).
+    ///
+    /// The result is clamped between [`i32::MIN`] and [`i32::MAX`], see [`Voltmeter::measure_uv`].
+    #[inline]
+    fn measure_diff_uv(
+        &mut self,
+        positive_channel: u8,
+        negative_channel: u8,
+    ) -> Result<i32, Self::Error> {
+        let nv = self.measure_diff_nv(positive_channel, negative_channel)?;
+        Ok((nv / 1_000).clamp(i32::MIN.into(), i32::MAX.into()) as i32)
+    }
+
+    /// Measures the voltage difference in mV (millivolts).
+    ///
+    /// The result is clamped between [`i16::MIN`] and [`i16::MAX`], see [`Voltmeter::measure_mv`].
+    #[inline]
+    fn measure_diff_mv(
+        &mut self,
+        positive_channel: u8,
+        negative_channel: u8,
+    ) -> Result<i16, Self::Error> {
+        let uv = self.measure_diff_uv(positive_channel, negative_channel)?;
+        Ok((uv / 1_000).clamp(i16::MIN.into(), i16::MAX.into()) as i16)
+    }
+}
+
+impl<T: DifferentialVoltmeter + ?Sized> DifferentialVoltmeter for &mut T {
+    #[inline]
+    fn measure_diff_nv(
+        &mut self,
+        positive_channel: u8,
+        negative_channel: u8,
+    ) -> Result<i64, Self::Error> {
+        T::measure_diff_nv(self, positive_channel, negative_channel)
+    }
+
+    #[inline]
+    fn measure_diff_uv(
+        &mut self,
+        positive_channel: u8,
+        negative_channel: u8,
+    ) -> Result<i32, Self::Error> {
+        T::measure_diff_uv(self, positive_channel, negative_channel)
+    }
+
+    #[inline]
+    fn measure_diff_mv(
+        &mut self,
+        positive_channel: u8,
+        negative_channel: u8,
+    ) -> Result<i16, Self::Error> {
+        T::measure_diff_mv(self, positive_channel, negative_channel)
+    }
+}
+
+/// Timer event used as an ADC trigger.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub enum TimerEvent {
+    /// Timer update event, typically on counter overflow or underflow.
+    Update,
+    /// Compare match on the given timer channel.
+    CompareChannel(u8),
+    /// Timer trigger output (TRGO).
+    Trigger,
+}
+
+/// Signal edge used as an ADC trigger.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub enum Edge {
+    /// Low to high transition.
+    Rising,
+    /// High to low transition.
+    Falling,
+    /// Any transition.
+    Both,
+}
+
+/// Source starting ADC conversions.
+///
+/// Timer and pin identifiers are HAL-defined.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub enum AdcTrigger {
+    /// Conversions are started by software, when a measurement is requested.
+    Software,
+    /// Conversions are started by a timer event.
+    Timer {
+        /// HAL-defined timer identifier.
+        timer_id: u8,
+        /// Timer event triggering the conversion.
+        event: TimerEvent,
+    },
+    /// Conversions are started by an edge on an external pin.
+    GpioEdge {
+        /// HAL-defined pin identifier.
+        pin_id: u8,
+        /// Edge triggering the conversion.
+        edge: Edge,
+    },
+}
+
+/// ADC with a configurable conversion trigger.
+///
+/// Hardware triggers allow sampling at precise intervals, e.g. driven by a timer, or in sync
+/// with an external event.
+pub trait ConfigurableAdcTrigger: ErrorType {
+    /// Set the source starting conversions.
+    ///
+    /// With any trigger other than [`AdcTrigger::Software`], measurement methods such as
+    /// [`Voltmeter::measure_nv`] block until the next trigger fires and the conversion completes.
+    ///
+    /// Returns an error of kind [`ErrorKind::InvalidInput`] if the trigger isn't supported.
+    fn set_trigger(&mut self, trigger: AdcTrigger) -> Result<(), Self::Error>;
+}
+
+impl<T: ConfigurableAdcTrigger + ?Sized> ConfigurableAdcTrigger for &mut T {
+    #[inline]
+    fn set_trigger(&mut self, trigger: AdcTrigger) -> Result<(), Self::Error> {
+        T::set_trigger(self, trigger)
+    }
+}
+
+/// Number of samples accumulated by hardware oversampling.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub enum OversamplingRatio {
+    /// 2 samples.
+    X2,
+    /// 4 samples.
+    X4,
+    /// 8 samples.
+    X8,
+    /// 16 samples.
+    X16,
+    /// 32 samples.
+    X32,
+    /// 64 samples.
+    X64,
+    /// 128 samples.
+    X128,
+    /// 256 samples.
+    X256,
+}
+
+impl OversamplingRatio {
+    /// Returns the number of samples accumulated.
+    #[inline]
+    pub const fn samples(self) -> u16 {
+        1 << self.mean_shift()
+    }
+
+    /// Returns the shift resulting in the mean of the accumulated samples, i.e. `log2(samples)`.
+    #[inline]
+    pub const fn mean_shift(self) -> u8 {
+        match self {
+            Self::X2 => 1,
+            Self::X4 => 2,
+            Self::X8 => 3,
+            Self::X16 => 4,
+            Self::X32 => 5,
+            Self::X64 => 6,
+            Self::X128 => 7,
+            Self::X256 => 8,
+        }
+    }
+}
+
+/// ADC with hardware oversampling.
+///
+/// When oversampling is enabled, the ADC accumulates several samples per conversion, reducing
+/// noise and increasing the effective resolution. Measurement methods such as
+/// [`Voltmeter::measure_nv`] return the oversampled result, scaled back to a voltage by the
+/// implementation.
+///
+/// Oversampling applies to every conversion, so in continuous mode or when conversions are
+/// transferred by DMA, each result is also an oversampled one, and the conversion rate is divided
+/// by the oversampling ratio. Changing the oversampling configuration while conversions are
+/// running is implementation-defined, and should be done with conversions stopped.
+pub trait AdcOversampling: ErrorType {
+    /// Enable oversampling.
+    ///
+    /// `shift` is the number of bits the accumulated value is right-shifted by: `0` keeps the sum
+    /// of the samples, and [`ratio.mean_shift()`](OversamplingRatio::mean_shift) results in their
+    /// mean.
+    ///
+    /// Returns an error of kind [`ErrorKind::InvalidInput`] if the ratio or shift isn't supported.
+    fn set_oversampling(&mut self, ratio: OversamplingRatio, shift: u8) -> Result<(), Self::Error>;
+
+    /// Disable oversampling.
+    fn disable_oversampling(&mut self) -> Result<(), Self::Error>;
+}
+
+impl<T: AdcOversampling + ?Sized> AdcOversampling for &mut T {
+    #[inline]
+    fn set_oversampling(&mut self, ratio: OversamplingRatio, shift: u8) -> Result<(), Self::Error> {
+        T::set_oversampling(self, ratio, shift)
+    }
+
+    #[inline]
+    fn disable_oversampling(&mut self) -> Result<(), Self::Error> {
+        T::disable_oversampling(self)
+    }
+}
+
+/// ADC with a configurable sample time per channel.
+///
+/// The sample time (or acquisition time) is the time the ADC sample-and-hold capacitor is
+/// connected to the input before a conversion. It must be long enough for the capacitor to charge
+/// through the source impedance, so sources with a higher impedance require more cycles. For an
+/// ADC with a resolution of `N` bits, the sample time must satisfy:
+///
+/// ``` text
+/// t_sample >= (R_source + R_ADC) * C_sample * ln(2^(N+1))
+/// ```
+///
+/// Where `R_source` is the source impedance, and `R_ADC` and `C_sample` are the ADC input
+/// resistance and sampling capacitance, found in the MCU datasheet.
+pub trait AdcSampleTime: ErrorType {
+    /// Set the sample time of `channel`, in ADC clock cycles.
+    ///
+    /// Channel indices are HAL-defined. If the hardware only supports a fixed set of sample times,
+    /// the next supported value above `cycles` is used. The new sample time takes effect before the
+    /// next conversion of this channel.
+    ///
+    /// Returns an error of kind [`ErrorKind::InvalidInput`] if the channel doesn't exist, or
+    /// `cycles` is above [`max_sample_time_cycles`](AdcSampleTime::max_sample_time_cycles).
+    fn set_sample_time(&mut self, channel: u8, cycles: u16) -> Result<(), Self::Error>;
+
+    /// Returns the maximum sample time supported, in ADC clock cycles.
+    fn max_sample_time_cycles(&self) -> u16;
+}
+
+impl<T: AdcSampleTime + ?Sized> AdcSampleTime for &mut T {
+    #[inline]
+    fn set_sample_time(&mut self, channel: u8, cycles: u16) -> Result<(), Self::Error> {
+        T::set_sample_time(self, channel, cycles)
+    }
+
+    #[inline]
+    fn max_sample_time_cycles(&self) -> u16 {
+        T::max_sample_time_cycles(self)
+    }
+}
+
+/// Read raw samples from an ADC.
+///
+/// Unlike [`Voltmeter`], which returns calibrated voltages, this returns the raw conversion result,
+/// without requiring the reference voltage to be known. This is useful for applications processing
+/// the signal itself, such as compression or signal analysis.
+///
+/// HALs can implement both traits, deriving the voltage from the raw sample when the reference
+/// voltage is known:
+///
+/// ```
+/// # use embedded_hal::adc::{AdcRaw, ErrorKind, ErrorType, Voltmeter};
+/// # struct Adc;
+/// # impl ErrorType for Adc {
+/// #     type Error = ErrorKind;
+/// # }
+/// # impl AdcRaw for Adc {
+/// #     fn read_raw(&mut self) -> Result<u32, Self::Error> {
+/// #         Ok(2048)
+/// #     }
+/// #     fn resolution_bits(&self) -> u8 {
+/// #         12
+/// #     }
+/// # }
+/// const REFERENCE_NV: i64 = 3_300_000_000;
+///
+/// impl Voltmeter for Adc {
+///     fn measure_nv(&mut self) -> Result<i64, Self::Error> {
+///         let raw = i64::from(self.read_raw()?);
+///         Ok(raw * REFERENCE_NV >> self.resolution_bits())
+///     }
+/// }
+///
+/// assert_eq!(Adc.measure_mv(), Ok(1650));
+/// ```
+pub trait AdcRaw: ErrorType {
+    /// Reads a raw sample.
+    ///
+    /// The sample is in the range `0..2^resolution_bits`, right-aligned.
+    fn read_raw(&mut self) -> Result<u32, Self::Error>;
+
+    /// Returns the resolution of the samples, in bits.
+    fn resolution_bits(&self) -> u8;
+}
+
+impl<T: AdcRaw + ?Sized> AdcRaw for &mut T {
+    #[inline]
+    fn read_raw(&mut self) -> Result<u32, Self::Error> {
+        T::read_raw(self)
+    }
+
+    #[inline]
+    fn resolution_bits(&self) -> u8 {
+        T::resolution_bits(self)
+    }
+}
+
+/// ADC with an analog watchdog.
+///
+/// The analog watchdog compares every conversion result with a low and a high threshold, and
+/// flags the conversions outside of the `low_nv..=high_nv` window, usually raising an interrupt.
+/// This allows monitoring a signal without checking every sample in software.
+pub trait AdcWatchdog: ErrorType {
+    /// Set the watchdog thresholds, in nanovolts.
+    ///
+    /// Implementations round the thresholds to the ADC resolution. Returns an error of kind
+    /// [`ErrorKind::InvalidInput`] if `low_nv` is greater than `high_nv`.
+    fn set_watchdog_thresholds(&mut self, low_nv: i64, high_nv: i64) -> Result<(), Self::Error>;
+
+    /// Enable the watchdog.
+    fn enable_watchdog(&mut self) -> Result<(), Self::Error>;
+
+    /// Disable the watchdog.
+    fn disable_watchdog(&mut self) -> Result<(), Self::Error>;
+
+    /// Returns `true` if a conversion result was outside of the thresholds since the last call.
+    ///
+    /// Implementations clear the watchdog flag when this returns `true`.
+    fn is_watchdog_triggered(&mut self) -> Result<bool, Self::Error>;
+}
+
+impl<T: AdcWatchdog + ?Sized> AdcWatchdog for &mut T {
+    #[inline]
+    fn set_watchdog_thresholds(&mut self, low_nv: i64, high_nv: i64) -> Result<(), Self::Error> {
+        T::set_watchdog_thresholds(self, low_nv, high_nv)
+    }
+
+    #[inline]
+    fn enable_watchdog(&mut self) -> Result<(), Self::Error> {
+        T::enable_watchdog(self)
+    }
+
+    #[inline]
+    fn disable_watchdog(&mut self) -> Result<(), Self::Error> {
+        T::disable_watchdog(self)
+    }
+
+    #[inline]
+    fn is_watchdog_triggered(&mut self) -> Result<bool, Self::Error> {
+        T::is_watchdog_triggered(self)
+    }
+}
+
+/// ADC scanning several channels in a single conversion sequence.
+///
+/// Many ADCs can convert a sequence of channels in hardware, usually storing the results with
+/// DMA. This gives samples of all channels taken close together, without software intervention
+/// between conversions.
+pub trait AdcScan: ErrorType {
+    /// Scan `channels` in order, storing the result of each channel in nV (nanovolts) in the
+    /// matching element of `results`.
+    ///
+    /// Channel indices are HAL-defined. Returns an error of kind [`ErrorKind::InvalidInput`] if
+    /// a channel doesn't exist, or if the hardware can't scan the given sequence.
+    ///
+    /// # Panics
+    ///
+    /// Implementations panic if `channels` and `results` have different lengths.
+    fn scan_channels(&mut self, channels: &[u8], results: &mut [i64]) -> Result<(), Self::Error>;
+}
+
+impl<T: AdcScan + ?Sized> AdcScan for &mut T {
+    #[inline]
+    fn scan_channels(&mut self, channels: &[u8], results: &mut [i64]) -> Result<(), Self::Error> {
+        T::scan_channels(self, channels, results)
+    }
+}
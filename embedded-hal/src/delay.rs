@@ -1,5 +1,7 @@
 //! Delays.
 
+use core::time::Duration;
+
 /// Nanoseconds per microsecond
 const NANOS_PER_MICRO: u32 = 1_000;
 /// Nanoseconds per millisecond
@@ -39,6 +41,27 @@ pub trait DelayNs {
 
         self.delay_ns(ms * NANOS_PER_MILLI);
     }
+
+    /// Pauses execution for at minimum `duration`. Pause can be longer
+    /// if the implementation requires it due to precision/timing issues.
+    ///
+    /// Durations longer than `u32::MAX` nanoseconds (~4.3 seconds) are split in several calls to `delay_ns`.
+    #[inline]
+    fn delay_duration(&mut self, duration: Duration) {
+        let mut ns = duration.as_nanos();
+
+        // Avoid overflow if the duration does not fit in a single `delay_ns` call
+        while ns > u128::from(u32::MAX) {
+            ns -= u128::from(u32::MAX);
+            self.delay_ns(u32::MAX);
+        }
+
+        // This is safe because we know that `ns <= u32::MAX`
+        #[allow(clippy::cast_possible_truncation)]
+        {
+            self.delay_ns(ns as u32);
+        }
+    }
 }
 
 impl<T> DelayNs for &mut T
@@ -59,4 +82,9 @@ where
     fn delay_ms(&mut self, ms: u32) {
         T::delay_ms(self, ms);
     }
+
+    #[inline]
+    fn delay_duration(&mut self, duration: Duration) {
+        T::delay_duration(self, duration);
+    }
 }
@@ -30,6 +30,8 @@ impl Error for core::convert::Infallible {
 #[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
 #[non_exhaustive]
 pub enum ErrorKind {
+    /// The requested operation or configuration is not supported by the pin.
+    Unsupported,
     /// A different error occurred. The original error may contain more information.
     Other,
 }
@@ -47,6 +49,10 @@ impl core::fmt::Display for ErrorKind {
     #[inline]
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
+            Self::Unsupported => write!(
+                f,
+                "The requested operation or configuration is not supported by the pin"
+            ),
             Self::Other => write!(
                 f,
                 "A different error occurred. The original error may contain more information"
@@ -222,3 +228,359 @@ impl<T: InputPin + ?Sized> InputPin for &mut T {
         T::is_low(self)
     }
 }
+
+/// [`InputPin`] wrapper debouncing the pin level, e.g. for mechanical buttons.
+///
+/// Each read samples the pin repeatedly, waiting `delay_us` microseconds between samples, until
+/// `samples` consecutive samples agree. The read only returns once the level is stable, so it
+/// takes at least `(samples - 1) * delay_us` microseconds, and doesn't return while the level
+/// keeps changing.
+///
+/// ```
+/// use embedded_hal::delay::DelayNs;
+/// use embedded_hal::digital::{DebouncedPin, InputPin};
+///
+/// fn is_pressed<P: InputPin, D: DelayNs>(button: P, delay: D) -> Result<bool, P::Error> {
+///     // 5 samples, 1 ms apart.
+///     let mut button = DebouncedPin::new(button, delay, 1_000, 5);
+///     button.is_low()
+/// }
+/// ```
+#[derive(Debug)]
+pub struct DebouncedPin<P, D> {
+    pin: P,
+    delay: D,
+    delay_us: u32,
+    samples: u8,
+}
+
+impl<P, D> DebouncedPin<P, D> {
+    /// Create a new `DebouncedPin` requiring `samples` consecutive equal samples, `delay_us`
+    /// microseconds apart.
+    ///
+    /// # Panics
+    ///
+    /// Panics in debug builds if `samples` is zero. In release builds, zero is treated as one,
+    /// i.e. no debouncing.
+    #[inline]
+    pub fn new(pin: P, delay: D, delay_us: u32, samples: u8) -> Self {
+        debug_assert!(samples > 0, "samples must not be zero");
+        Self {
+            pin,
+            delay,
+            delay_us,
+            samples: samples.max(1),
+        }
+    }
+
+    /// Consumes the `DebouncedPin`, returning the pin and delay.
+    #[inline]
+    pub fn into_inner(self) -> (P, D) {
+        (self.pin, self.delay)
+    }
+}
+
+impl<P: InputPin, D: crate::delay::DelayNs> DebouncedPin<P, D> {
+    fn debounced_is_high(&mut self) -> Result<bool, P::Error> {
+        let mut level = self.pin.is_high()?;
+        let mut stable = 1;
+        while stable < self.samples {
+            self.delay.delay_us(self.delay_us);
+            let sample = self.pin.is_high()?;
+            if sample == level {
+                stable += 1;
+            } else {
+                level = sample;
+                stable = 1;
+            }
+        }
+        Ok(level)
+    }
+}
+
+impl<P: ErrorType, D> ErrorType for DebouncedPin<P, D> {
+    type Error = P::Error;
+}
+
+impl<P: InputPin, D: crate::delay::DelayNs> InputPin for DebouncedPin<P, D> {
+    #[inline]
+    fn is_high(&mut self) -> Result<bool, Self::Error> {
+        self.debounced_is_high()
+    }
+
+    #[inline]
+    fn is_low(&mut self) -> Result<bool, Self::Error> {
+        self.debounced_is_high().map(|high| !high)
+    }
+}
+
+/// Output drive strength.
+///
+/// The mapping of these levels to actual output current or slew rate is implementation-defined.
+/// Implementations that support fewer levels should round to the nearest supported one.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub enum DriveStrength {
+    /// Lowest drive strength.
+    Low,
+    /// Medium drive strength.
+    Medium,
+    /// High drive strength.
+    High,
+    /// Highest drive strength.
+    VeryHigh,
+}
+
+/// Configuration of the electrical characteristics of an output pin.
+///
+/// Lower drive strengths and slower slew rates reduce EMI and ringing, at the expense of
+/// slower edges.
+///
+/// *NOTE* these settings only affect the electrical characteristics of the pin, not its logical state.
+pub trait ConfigurePin: ErrorType {
+    /// Set the output drive strength of the pin.
+    fn set_drive_strength(&mut self, strength: DriveStrength) -> Result<(), Self::Error>;
+
+    /// Set the slew rate of the pin to fast (`true`) or slow (`false`).
+    fn set_slew_rate(&mut self, fast: bool) -> Result<(), Self::Error>;
+}
+
+impl<T: ConfigurePin + ?Sized> ConfigurePin for &mut T {
+    #[inline]
+    fn set_drive_strength(&mut self, strength: DriveStrength) -> Result<(), Self::Error> {
+        T::set_drive_strength(self, strength)
+    }
+
+    #[inline]
+    fn set_slew_rate(&mut self, fast: bool) -> Result<(), Self::Error> {
+        T::set_slew_rate(self, fast)
+    }
+}
+
+/// Set of electrical capabilities of a pin.
+///
+/// This is a set of flags, which can be combined with `|`.
+///
+/// ```
+/// use embedded_hal::digital::PinCapabilities;
+///
+/// let pin = PinCapabilities::SOURCE_25MA | PinCapabilities::SINK_25MA;
+/// assert!(pin.satisfies(PinCapabilities::SOURCE_8MA));
+/// assert!(!PinCapabilities::SINK_8MA.satisfies(pin));
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub struct PinCapabilities(u16);
+
+impl PinCapabilities {
+    /// The pin can source at least 2 mA.
+    pub const SOURCE_2MA: Self = Self(1 << 0);
+    /// The pin can source at least 8 mA.
+    pub const SOURCE_8MA: Self = Self(1 << 1);
+    /// The pin can source at least 25 mA.
+    pub const SOURCE_25MA: Self = Self(1 << 2);
+    /// The pin can sink at least 2 mA.
+    pub const SINK_2MA: Self = Self(1 << 3);
+    /// The pin can sink at least 8 mA.
+    pub const SINK_8MA: Self = Self(1 << 4);
+    /// The pin can sink at least 25 mA.
+    pub const SINK_25MA: Self = Self(1 << 5);
+    /// The pin can only be used as an open-drain output.
+    ///
+    /// Open-drain-only pins can't source current, so they shouldn't have any `SOURCE_*` flag.
+    pub const OPEN_DRAIN_ONLY: Self = Self(1 << 6);
+
+    /// Returns an empty set of capabilities.
+    #[inline]
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+
+    /// Returns the raw value of the flags.
+    #[inline]
+    pub const fn bits(&self) -> u16 {
+        self.0
+    }
+
+    /// Returns `true` if all flags in `other` are set in `self`.
+    #[inline]
+    pub const fn contains(&self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns `true` if a pin with these capabilities meets the `required` capabilities.
+    ///
+    /// Unlike [`contains`](Self::contains), this takes into account that a higher current
+    /// capability implies the lower ones: a pin with [`SOURCE_25MA`](Self::SOURCE_25MA)
+    /// satisfies a requirement of [`SOURCE_8MA`](Self::SOURCE_8MA).
+    ///
+    /// [`OPEN_DRAIN_ONLY`](Self::OPEN_DRAIN_ONLY) describes a limitation rather than a capability,
+    /// so it is ignored.
+    #[inline]
+    pub const fn satisfies(&self, required: Self) -> bool {
+        const fn source_ma(caps: PinCapabilities) -> u8 {
+            if caps.contains(PinCapabilities::SOURCE_25MA) {
+                25
+            } else if caps.contains(PinCapabilities::SOURCE_8MA) {
+                8
+            } else if caps.contains(PinCapabilities::SOURCE_2MA) {
+                2
+            } else {
+                0
+            }
+        }
+
+        const fn sink_ma(caps: PinCapabilities) -> u8 {
+            if caps.contains(PinCapabilities::SINK_25MA) {
+                25
+            } else if caps.contains(PinCapabilities::SINK_8MA) {
+                8
+            } else if caps.contains(PinCapabilities::SINK_2MA) {
+                2
+            } else {
+                0
+            }
+        }
+
+        source_ma(*self) >= source_ma(required) && sink_ma(*self) >= sink_ma(required)
+    }
+}
+
+impl core::ops::BitOr for PinCapabilities {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl core::ops::BitOrAssign for PinCapabilities {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+/// Query the electrical capabilities of a pin.
+///
+/// Capabilities are a hardware property and don't change, so this only requires `&self`.
+/// Drivers for high-current loads can use this to check at runtime that a pin meets their requirements.
+pub trait QueryPinCapabilities {
+    /// Returns the capabilities of the pin.
+    fn capabilities(&self) -> PinCapabilities;
+}
+
+impl<T: QueryPinCapabilities + ?Sized> QueryPinCapabilities for &T {
+    #[inline]
+    fn capabilities(&self) -> PinCapabilities {
+        T::capabilities(self)
+    }
+}
+
+impl<T: QueryPinCapabilities + ?Sized> QueryPinCapabilities for &mut T {
+    #[inline]
+    fn capabilities(&self) -> PinCapabilities {
+        T::capabilities(self)
+    }
+}
+
+/// Edge waking the system up from a sleep mode.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub enum WakeupEdge {
+    /// Wake up on a transition from low to high.
+    Rising,
+    /// Wake up on a transition from high to low.
+    Falling,
+    /// Wake up on any transition.
+    Any,
+}
+
+/// Pin able to wake the system up from a sleep mode.
+///
+/// This is separate from [`Wait`](https://docs.rs/embedded-hal-async/latest/embedded_hal_async/digital/trait.Wait.html):
+/// wakeup is configured before entering the sleep mode, while `Wait` waits for an event while the
+/// system is running. Entering the sleep mode itself is out of scope of this trait.
+pub trait WakeupSource: ErrorType {
+    /// Enable waking the system up on the given edge.
+    fn enable_wakeup(&mut self, edge: WakeupEdge) -> Result<(), Self::Error>;
+
+    /// Disable waking the system up from this pin.
+    fn disable_wakeup(&mut self) -> Result<(), Self::Error>;
+
+    /// Returns `true` if this pin caused the last wakeup.
+    fn is_wakeup_source(&mut self) -> Result<bool, Self::Error>;
+}
+
+impl<T: WakeupSource + ?Sized> WakeupSource for &mut T {
+    #[inline]
+    fn enable_wakeup(&mut self, edge: WakeupEdge) -> Result<(), Self::Error> {
+        T::enable_wakeup(self, edge)
+    }
+
+    #[inline]
+    fn disable_wakeup(&mut self) -> Result<(), Self::Error> {
+        T::disable_wakeup(self)
+    }
+
+    #[inline]
+    fn is_wakeup_source(&mut self) -> Result<bool, Self::Error> {
+        T::is_wakeup_source(self)
+    }
+}
+
+/// Pin whose alternate function can be changed at runtime.
+///
+/// Pins are usually configured once at initialization, but some applications need to remap them
+/// at runtime, e.g. to drive a bus pin as a GPIO while the peripheral is asleep, or to multiplex
+/// several peripherals on the same pin.
+pub trait AlternateFunction: ErrorType {
+    /// Connect the pin to the alternate function `af`.
+    ///
+    /// Alternate function numbers are platform-defined. Returns an error of kind
+    /// [`ErrorKind::Unsupported`] if `af` isn't available on this pin.
+    fn set_alternate_function(&mut self, af: u8) -> Result<(), Self::Error>;
+
+    /// Return the pin to GPIO mode, in the configuration it had before the alternate function was set.
+    fn restore_gpio(&mut self) -> Result<(), Self::Error>;
+}
+
+impl<T: AlternateFunction + ?Sized> AlternateFunction for &mut T {
+    #[inline]
+    fn set_alternate_function(&mut self, af: u8) -> Result<(), Self::Error> {
+        T::set_alternate_function(self, af)
+    }
+
+    #[inline]
+    fn restore_gpio(&mut self) -> Result<(), Self::Error> {
+        T::restore_gpio(self)
+    }
+}
+
+/// Group of pins sharing an interrupt.
+///
+/// On many MCUs, several GPIO pins share an interrupt vector. The interrupt handler uses this
+/// trait to find out which pins triggered, and dispatch to the corresponding drivers.
+///
+/// Pins are identified by their bit position in the masks, e.g. pin 3 of the group is bit
+/// `1 << 3`. The mapping between bits and pins is platform-defined.
+pub trait InterruptGroup: ErrorType {
+    /// Returns a bitmask of the pins with a pending interrupt.
+    fn pending_pins(&mut self) -> Result<u32, Self::Error>;
+
+    /// Clear the pending interrupts of the pins in `mask`.
+    fn clear_pending(&mut self, mask: u32) -> Result<(), Self::Error>;
+}
+
+impl<T: InterruptGroup + ?Sized> InterruptGroup for &mut T {
+    #[inline]
+    fn pending_pins(&mut self) -> Result<u32, Self::Error> {
+        T::pending_pins(self)
+    }
+
+    #[inline]
+    fn clear_pending(&mut self, mask: u32) -> Result<(), Self::Error> {
+        T::clear_pending(self, mask)
+    }
+}
@@ -203,6 +203,14 @@ pub enum ErrorKind {
     NoAcknowledge(NoAcknowledgeSource),
     /// The peripheral receive buffer was overrun.
     Overrun,
+    /// The data received from the device is invalid, e.g. an SMBus block longer than expected.
+    InvalidData,
+    /// The input is invalid, e.g. an SMBus block longer than 255 bytes.
+    InvalidInput,
+    /// The operation is not supported by the implementation.
+    Unsupported,
+    /// The operation didn't complete in time, e.g. because a device stretches the clock forever.
+    TimedOut,
     /// A different error occurred. The original error may contain more information.
     Other,
 }
@@ -242,6 +250,10 @@ impl core::fmt::Display for ErrorKind {
             Self::ArbitrationLoss => write!(f, "The arbitration was lost"),
             Self::NoAcknowledge(s) => s.fmt(f),
             Self::Overrun => write!(f, "The peripheral receive buffer was overrun"),
+            Self::InvalidData => write!(f, "The data received from the device is invalid"),
+            Self::InvalidInput => write!(f, "The input is invalid"),
+            Self::Unsupported => write!(f, "The operation is not supported"),
+            Self::TimedOut => write!(f, "The operation timed out"),
             Self::Other => write!(
                 f,
                 "A different error occurred. The original error may contain more information"
@@ -290,11 +302,95 @@ pub trait AddressMode: Copy + 'static + private::Sealed {}
 ///
 /// Should be specified as `0b0011_0010` or `0x32`, NOT `0x64` or `0x65`. Care should be taken by both HAL and driver
 /// crate writers to use this scheme consistently.
+///
+/// The addresses `0x00..=0x07` and `0x78..=0x7F` are reserved by the I2C specification, and the behavior
+/// of using them as a device address is undefined. Use [`CheckedSevenBitAddress`] to validate an address.
 pub type SevenBitAddress = u8;
 
 /// 10-bit address mode type.
 pub type TenBitAddress = u16;
 
+/// 7-bit address, checked to be outside of the reserved address ranges.
+///
+/// The I2C specification reserves the addresses `0x00..=0x07` and `0x78..=0x7F` for special
+/// purposes (general call, START byte, CBUS, high-speed mode, 10-bit addressing, etc.).
+/// Drivers can use this type to validate a user-provided address when constructing the driver,
+/// and convert it into a [`SevenBitAddress`] to use it with the [`I2c`] trait.
+///
+/// Like [`SevenBitAddress`], the address is stored in **right-aligned** form.
+///
+/// ```
+/// use embedded_hal::i2c::{CheckedSevenBitAddress, SevenBitAddress};
+///
+/// let address = CheckedSevenBitAddress::new(0x42).unwrap();
+/// assert_eq!(SevenBitAddress::from(address), 0x42);
+/// assert_eq!(format!("{address}"), "0x42");
+///
+/// // General call address is reserved.
+/// assert_eq!(CheckedSevenBitAddress::new(0x00), None);
+/// // Out of range of a 7-bit address.
+/// assert_eq!(CheckedSevenBitAddress::new(0x80), None);
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub struct CheckedSevenBitAddress(SevenBitAddress);
+
+impl CheckedSevenBitAddress {
+    /// Tries to create a `CheckedSevenBitAddress` from a raw right-aligned 7-bit address.
+    ///
+    /// This will return `None` if `addr` is in one of the reserved address ranges
+    /// (`0x00..=0x07` or `0x78..=0x7F`), or out of range of a 7-bit address (`> 0x7F`).
+    #[inline]
+    #[must_use]
+    pub const fn new(addr: SevenBitAddress) -> Option<Self> {
+        if Self::is_reserved(addr) || addr > 0x7F {
+            None
+        } else {
+            Some(Self(addr))
+        }
+    }
+
+    /// Creates a new `CheckedSevenBitAddress` without checking if it is outside the reserved ranges.
+    ///
+    /// # Safety
+    /// Using this method can create a reserved or out of range address. Addressing a reserved
+    /// address has special meaning on the bus, and the resulting behavior is undefined from
+    /// the point of view of drivers and HALs.
+    #[inline]
+    #[must_use]
+    pub const unsafe fn new_unchecked(addr: SevenBitAddress) -> Self {
+        Self(addr)
+    }
+
+    /// Returns whether the right-aligned 7-bit address `addr` is reserved by the I2C specification.
+    #[inline]
+    #[must_use]
+    pub const fn is_reserved(addr: SevenBitAddress) -> bool {
+        matches!(addr, 0x00..=0x07 | 0x78..=0x7F)
+    }
+
+    /// Returns this address as a raw right-aligned 7-bit address.
+    #[inline]
+    #[must_use]
+    pub const fn as_raw(&self) -> SevenBitAddress {
+        self.0
+    }
+}
+
+impl From<CheckedSevenBitAddress> for SevenBitAddress {
+    #[inline]
+    fn from(addr: CheckedSevenBitAddress) -> Self {
+        addr.0
+    }
+}
+
+impl core::fmt::Display for CheckedSevenBitAddress {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:#04x}", self.0)
+    }
+}
+
 impl AddressMode for SevenBitAddress {}
 
 impl AddressMode for TenBitAddress {}
@@ -392,7 +488,9 @@ pub trait I2c<A: AddressMode = SevenBitAddress>: ErrorType {
     /// Transaction contract:
     /// - Before executing the first operation an ST is sent automatically. This is followed by SAD+R/W as appropriate.
     /// - Data from adjacent operations of the same type are sent after each other without an SP or SR.
-    /// - Between adjacent operations of a different type an SR and SAD+R/W is sent.
+    /// - Between adjacent operations of a different type an SR and SAD+R/W is sent implicitly, to
+    ///   the same address.
+    /// - No SP is ever sent between operations: the whole transaction is atomic on the bus.
     /// - After executing the last operation an SP is sent automatically.
     /// - At the end of each read operation (before SP or SR), the master does not send an acknowledge for the last byte.
     ///
@@ -432,3 +530,366 @@ impl<A: AddressMode, T: I2c<A> + ?Sized> I2c<A> for &mut T {
         T::transaction(self, address, operations)
     }
 }
+
+/// I2C bus hang detection and recovery.
+///
+/// An I2C bus can hang when a slave device is reset (or the master is reset) in the middle of a transfer:
+/// the slave may keep holding SDA low, waiting for clock pulses that will never come. Implementations
+/// usually need direct access to the SDA and SCL pins as GPIOs to provide these methods, which they can
+/// manage as they see fit.
+pub trait I2cRecovery: ErrorType {
+    /// Returns whether the bus is busy, i.e. SDA or SCL is held low while the master is idle.
+    fn is_bus_busy(&mut self) -> Result<bool, Self::Error>;
+
+    /// Attempts to recover a hung bus.
+    ///
+    /// The standard recovery procedure is to clock SCL up to 9 times until the slave releases SDA,
+    /// then generate a stop condition. Implementations may use any other procedure supported by the
+    /// hardware (e.g. a dedicated bus clear feature).
+    ///
+    /// An error of kind [`ErrorKind::Bus`] should be returned if the bus is still busy afterwards.
+    fn recover_bus(&mut self) -> Result<(), Self::Error>;
+
+    /// Returns whether the SDA line is high. Intended for diagnostics.
+    fn is_sda_high(&mut self) -> Result<bool, Self::Error>;
+
+    /// Returns whether the SCL line is high. Intended for diagnostics.
+    fn is_scl_high(&mut self) -> Result<bool, Self::Error>;
+}
+
+impl<T: I2cRecovery + ?Sized> I2cRecovery for &mut T {
+    #[inline]
+    fn is_bus_busy(&mut self) -> Result<bool, Self::Error> {
+        T::is_bus_busy(self)
+    }
+
+    #[inline]
+    fn recover_bus(&mut self) -> Result<(), Self::Error> {
+        T::recover_bus(self)
+    }
+
+    #[inline]
+    fn is_sda_high(&mut self) -> Result<bool, Self::Error> {
+        T::is_sda_high(self)
+    }
+
+    #[inline]
+    fn is_scl_high(&mut self) -> Result<bool, Self::Error> {
+        T::is_scl_high(self)
+    }
+}
+
+/// SMBus Address Resolution Protocol (ARP).
+///
+/// SMBus ARP (SMBus specification, section 6.6) allows dynamically assigning addresses to devices,
+/// which is needed for systems with multiple identical devices sharing the same default address.
+/// Each ARP-capable device is identified by a 128-bit unique device identifier (UDID).
+///
+/// This trait is only available with the `heapless` Cargo feature.
+#[cfg(feature = "heapless")]
+#[cfg_attr(docsrs, doc(cfg(feature = "heapless")))]
+pub trait SmBusArp: I2c<SevenBitAddress> {
+    /// Discover the ARP-capable devices on the bus, using the ARP Get UDID command.
+    ///
+    /// For each device, returns its current 7-bit address and the 17 bytes returned by Get UDID:
+    /// the 16-byte UDID, most significant byte first, followed by the device address byte.
+    ///
+    /// At most 8 devices are returned.
+    fn arp_get_udid_list(&mut self) -> Result<heapless::Vec<(u8, [u8; 17]), 8>, Self::Error>;
+
+    /// Assign the 7-bit address `new_addr` to the device identified by `udid`, using the ARP
+    /// Assign Address command.
+    ///
+    /// `udid` is in the format returned by [`arp_get_udid_list`](SmBusArp::arp_get_udid_list).
+    fn arp_assign_address(&mut self, udid: &[u8; 17], new_addr: u8) -> Result<(), Self::Error>;
+}
+
+#[cfg(feature = "heapless")]
+impl<T: SmBusArp + ?Sized> SmBusArp for &mut T {
+    #[inline]
+    fn arp_get_udid_list(&mut self) -> Result<heapless::Vec<(u8, [u8; 17]), 8>, Self::Error> {
+        T::arp_get_udid_list(self)
+    }
+
+    #[inline]
+    fn arp_assign_address(&mut self, udid: &[u8; 17], new_addr: u8) -> Result<(), Self::Error> {
+        T::arp_assign_address(self, udid, new_addr)
+    }
+}
+
+/// SMBus Alert Response Address (ARA), `0x0C`.
+pub const SMBUS_ALERT_RESPONSE_ADDRESS: SevenBitAddress = 0x0C;
+
+/// SMBus alert response.
+///
+/// SMBus devices can signal the host that they need attention by asserting the optional
+/// `SMBALERT#` line, which can be shared by multiple devices. The host then reads one byte from
+/// the Alert Response Address ([`SMBUS_ALERT_RESPONSE_ADDRESS`]), to which every alerting device
+/// responds with its own address, as defined in the "SMBALERT#" part of the SMBus 2.0 specification.
+/// Arbitration ensures the device with the lowest address wins, and stops asserting `SMBALERT#`.
+///
+/// This trait is implemented for all [`I2c`] implementations.
+///
+/// # Example
+///
+/// Drain all alerting devices:
+///
+/// ```
+/// use embedded_hal::i2c::{Error, ErrorKind, I2c, NoAcknowledgeSource, SmBusAlert};
+///
+/// fn handle_alerts<I: I2c>(i2c: &mut I) -> Result<(), I::Error> {
+///     loop {
+///         match i2c.smbus_alert_response() {
+///             Ok(address) => { /* handle the alert of the device at `address` */ }
+///             Err(e) if e.kind() == ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address) => {
+///                 return Ok(());
+///             }
+///             Err(e) => return Err(e),
+///         }
+///     }
+/// }
+/// ```
+pub trait SmBusAlert: I2c<SevenBitAddress> {
+    /// Read the address of the alerting device with the highest priority (lowest address).
+    ///
+    /// Returns the 7-bit address of the device. When no device is alerting anymore, the
+    /// read is not acknowledged, and an error of kind
+    /// [`ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address)`](ErrorKind::NoAcknowledge)
+    /// is returned.
+    #[inline]
+    fn smbus_alert_response(&mut self) -> Result<SevenBitAddress, Self::Error> {
+        let mut buf = [0u8; 1];
+        self.read(SMBUS_ALERT_RESPONSE_ADDRESS, &mut buf)?;
+        // The address is in the upper 7 bits, the LSB is unspecified.
+        Ok(buf[0] >> 1)
+    }
+}
+
+impl<T: I2c<SevenBitAddress> + ?Sized> SmBusAlert for T {}
+
+/// SMBus Host address, `0x08`.
+pub const SMBUS_HOST_ADDRESS: SevenBitAddress = 0x08;
+
+/// SMBus Host Notify.
+///
+/// With the Host Notify protocol (SMBus specification, section 6.5.16), a device needing attention
+/// becomes a master and writes its own address and a 16-bit status word to the SMBus Host address
+/// ([`SMBUS_HOST_ADDRESS`]).
+///
+/// Receiving these messages requires the controller to respond as a target at
+/// [`SMBUS_HOST_ADDRESS`], which is implemented by the HAL. See
+/// `embedded_hal_async::i2c::SmBusHostNotify` to wait for messages instead of polling.
+pub trait SmBusHost: ErrorType {
+    /// Read the last Host Notify message received by the controller.
+    ///
+    /// Returns the 7-bit address of the notifying device and its status word, or `None` if no
+    /// message was received since the last call.
+    fn smbus_host_notify_response(&mut self)
+        -> Result<Option<(SevenBitAddress, u16)>, Self::Error>;
+}
+
+impl<T: SmBusHost + ?Sized> SmBusHost for &mut T {
+    #[inline]
+    fn smbus_host_notify_response(
+        &mut self,
+    ) -> Result<Option<(SevenBitAddress, u16)>, Self::Error> {
+        T::smbus_host_notify_response(self)
+    }
+}
+
+/// SMBus block and process call commands.
+///
+/// These implement the "Block Write" and "Process Call" commands of the SMBus specification,
+/// [`SmBusBlockRead`] implements the commands reading a block. Blocks are prefixed with their byte
+/// count. Process calls send data to a device and read its response in a single transaction, with
+/// a repeated start condition in between.
+///
+/// Packet error checking (PEC) is not used: no PEC byte is sent or read.
+///
+/// This trait is implemented for all [`I2c`] implementations.
+pub trait SmBusExt: I2c<SevenBitAddress> {
+    /// Send the command `cmd` followed by the data block `data` to the device at `address`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `data` is longer than 255 bytes, which can't be represented by the byte count.
+    #[inline]
+    fn smbus_block_write(
+        &mut self,
+        address: SevenBitAddress,
+        cmd: u8,
+        data: &[u8],
+    ) -> Result<(), Self::Error> {
+        let len = u8::try_from(data.len()).expect("SMBus blocks are at most 255 bytes long");
+        self.transaction(
+            address,
+            &mut [Operation::Write(&[cmd, len]), Operation::Write(data)],
+        )
+    }
+
+    /// Send the command `cmd` with the data word `send`, and read the data word returned by the
+    /// device at `address`.
+    ///
+    /// Data words are sent and read little-endian, as defined by the SMBus specification.
+    #[inline]
+    fn smbus_process_call(
+        &mut self,
+        address: SevenBitAddress,
+        cmd: u8,
+        send: u16,
+    ) -> Result<u16, Self::Error> {
+        let [lo, hi] = send.to_le_bytes();
+        let mut buf = [0u8; 2];
+        self.write_read(address, &[cmd, lo, hi], &mut buf)?;
+        Ok(u16::from_le_bytes(buf))
+    }
+}
+
+impl<T: I2c<SevenBitAddress> + ?Sized> SmBusExt for T {}
+
+/// SMBus block reads.
+///
+/// In the "Block Read" and "Block Write-Block Read Process Call" commands of the SMBus
+/// specification, the device sends a byte count followed by the data block, and the controller
+/// must read exactly that number of bytes. This can't be expressed as an [`I2c`] transaction,
+/// whose read lengths are fixed beforehand, so this trait is implemented by the HAL.
+///
+/// Packet error checking (PEC) is not used: no PEC byte is sent or read.
+pub trait SmBusBlockRead: ErrorType {
+    /// Send the command `cmd`, and read the data block returned by the device at `address`
+    /// into `buf`.
+    ///
+    /// The byte count is read first, then exactly that number of bytes. Returns the number of
+    /// bytes in the block.
+    ///
+    /// If the block is longer than `buf`, the transfer is ended and an error of kind
+    /// [`ErrorKind::InvalidData`] is returned.
+    fn smbus_block_read(
+        &mut self,
+        address: SevenBitAddress,
+        cmd: u8,
+        buf: &mut [u8],
+    ) -> Result<usize, Self::Error>;
+
+    /// Send the command `cmd` with the data block `send`, and read the data block returned by the
+    /// device at `address` into `recv`, in a single transaction with a repeated start condition in
+    /// between.
+    ///
+    /// The byte count of the response is read first, then exactly that number of bytes. Returns
+    /// the number of bytes in the response.
+    ///
+    /// Returns an error of kind [`ErrorKind::InvalidInput`], without starting the transfer, if
+    /// `send` is longer than 255 bytes, which can't be represented by the byte count. If the
+    /// response is longer than `recv`, the transfer is ended and an error of kind
+    /// [`ErrorKind::InvalidData`] is returned.
+    fn smbus_block_process_call(
+        &mut self,
+        address: SevenBitAddress,
+        cmd: u8,
+        send: &[u8],
+        recv: &mut [u8],
+    ) -> Result<usize, Self::Error>;
+}
+
+impl<T: SmBusBlockRead + ?Sized> SmBusBlockRead for &mut T {
+    #[inline]
+    fn smbus_block_read(
+        &mut self,
+        address: SevenBitAddress,
+        cmd: u8,
+        buf: &mut [u8],
+    ) -> Result<usize, Self::Error> {
+        T::smbus_block_read(self, address, cmd, buf)
+    }
+
+    #[inline]
+    fn smbus_block_process_call(
+        &mut self,
+        address: SevenBitAddress,
+        cmd: u8,
+        send: &[u8],
+        recv: &mut [u8],
+    ) -> Result<usize, Self::Error> {
+        T::smbus_block_process_call(self, address, cmd, send, recv)
+    }
+}
+
+/// Register access for I2C devices with 8-bit register addresses.
+///
+/// Many I2C devices expose a register map, written by sending the register address followed by
+/// the data, and read by sending the register address followed by a read, with a repeated start
+/// condition in between. Most of them auto-increment the register address, allowing burst reads
+/// of consecutive registers.
+///
+/// Auto-increment is device-specific, and not part of the I2C standard: check the device datasheet
+/// before using [`read_regs`](RegisterDevice::read_regs) or [`write_regs`](RegisterDevice::write_regs).
+/// Some devices (e.g. BME280, MPU-6050) always auto-increment, others only when a bit of the
+/// register address is set, and others wrap around within a register block.
+///
+/// This trait is implemented for all [`I2c`] implementations.
+///
+/// ```
+/// use embedded_hal::i2c::{I2c, RegisterDevice};
+///
+/// const ADDR: u8 = 0x48;
+/// const REG_CONFIG: u8 = 0x01;
+///
+/// fn enable<I: I2c>(i2c: &mut I) -> Result<(), I::Error> {
+///     let config = i2c.read_reg(ADDR, REG_CONFIG)?;
+///     i2c.write_reg(ADDR, REG_CONFIG, config | 0x01)
+/// }
+/// ```
+pub trait RegisterDevice: I2c<SevenBitAddress> {
+    /// Read the register `reg` of the device at `address`.
+    #[inline]
+    fn read_reg(&mut self, address: SevenBitAddress, reg: u8) -> Result<u8, Self::Error> {
+        let mut buf = [0u8; 1];
+        self.write_read(address, &[reg], &mut buf)?;
+        Ok(buf[0])
+    }
+
+    /// Write `value` to the register `reg` of the device at `address`.
+    #[inline]
+    fn write_reg(
+        &mut self,
+        address: SevenBitAddress,
+        reg: u8,
+        value: u8,
+    ) -> Result<(), Self::Error> {
+        self.write(address, &[reg, value])
+    }
+
+    /// Read consecutive registers of the device at `address`, starting at `reg`, into `buf`.
+    ///
+    /// This relies on the device auto-incrementing the register address. It reads the whole
+    /// block in a single transaction, which is faster than reading the registers one by one and
+    /// ensures multi-byte values are read consistently.
+    #[inline]
+    fn read_regs(
+        &mut self,
+        address: SevenBitAddress,
+        reg: u8,
+        buf: &mut [u8],
+    ) -> Result<(), Self::Error> {
+        self.write_read(address, &[reg], buf)
+    }
+
+    /// Write `data` to consecutive registers of the device at `address`, starting at `reg`.
+    ///
+    /// This relies on the device auto-incrementing the register address. `reg` and `data` are
+    /// sent in a single write, without copying them to a temporary buffer.
+    #[inline]
+    fn write_regs(
+        &mut self,
+        address: SevenBitAddress,
+        reg: u8,
+        data: &[u8],
+    ) -> Result<(), Self::Error> {
+        self.transaction(
+            address,
+            &mut [Operation::Write(&[reg]), Operation::Write(data)],
+        )
+    }
+}
+
+impl<T: I2c<SevenBitAddress> + ?Sized> RegisterDevice for T {}
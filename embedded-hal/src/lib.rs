@@ -1,12 +1,17 @@
 #![doc = include_str!("../README.md")]
 #![warn(missing_docs)]
 #![no_std]
+#![cfg_attr(docsrs, feature(doc_cfg))]
 
+pub mod adc;
 pub mod delay;
 pub mod digital;
 pub mod i2c;
 pub mod pwm;
 pub mod spi;
+#[cfg(feature = "test-utils")]
+#[cfg_attr(docsrs, doc(cfg(feature = "test-utils")))]
+pub mod test_utils;
 
 mod private {
     use crate::i2c::{SevenBitAddress, TenBitAddress};
@@ -150,3 +150,207 @@ impl<T: SetDutyCycle + ?Sized> SetDutyCycle for &mut T {
         T::set_duty_cycle_percent(self, percent)
     }
 }
+
+/// PWM channel with a complementary output and dead-time insertion.
+///
+/// This is typically used to drive half-bridges (H-bridge motor drivers, power converters, etc.),
+/// where the main and complementary outputs switch the high and low side of the bridge. The dead
+/// time is the interval during which both outputs are inactive around each edge, preventing
+/// shoot-through.
+pub trait ComplementaryPwm: SetDutyCycle {
+    /// Get the maximum dead time supported by the hardware, in nanoseconds.
+    fn max_deadtime_ns(&mut self) -> u32;
+
+    /// Set the duty cycle to `duty / max_duty` on the main output, and configure the
+    /// complementary output with `deadtime_ns` nanoseconds of dead time around each edge.
+    ///
+    /// Both outputs are updated simultaneously.
+    ///
+    /// The caller is responsible for ensuring that the duty cycle value is less than or equal to the maximum duty cycle value,
+    /// as reported by [`max_duty_cycle`].
+    ///
+    /// `deadtime_ns` values greater than [`max_deadtime_ns`] are clamped to [`max_deadtime_ns`] without error.
+    /// Implementations may round the dead time up to the next value representable in hardware.
+    ///
+    /// [`max_duty_cycle`]: SetDutyCycle::max_duty_cycle
+    /// [`max_deadtime_ns`]: ComplementaryPwm::max_deadtime_ns
+    fn set_duty_with_deadtime(&mut self, duty: u16, deadtime_ns: u32) -> Result<(), Self::Error>;
+
+    /// Enable or disable the complementary output.
+    ///
+    /// When disabled, the complementary output is held inactive and only the main output is driven.
+    fn enable_complementary(&mut self, enable: bool) -> Result<(), Self::Error>;
+}
+
+impl<T: ComplementaryPwm + ?Sized> ComplementaryPwm for &mut T {
+    #[inline]
+    fn max_deadtime_ns(&mut self) -> u32 {
+        T::max_deadtime_ns(self)
+    }
+
+    #[inline]
+    fn set_duty_with_deadtime(&mut self, duty: u16, deadtime_ns: u32) -> Result<(), Self::Error> {
+        T::set_duty_with_deadtime(self, duty, deadtime_ns)
+    }
+
+    #[inline]
+    fn enable_complementary(&mut self, enable: bool) -> Result<(), Self::Error> {
+        T::enable_complementary(self, enable)
+    }
+}
+
+/// PWM counter mode.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub enum PwmCountMode {
+    /// Edge-aligned mode: the counter counts up and wraps around.
+    ///
+    /// All channels switch on at the start of the period.
+    EdgeAligned,
+    /// Center-aligned mode: the counter counts up, then down.
+    ///
+    /// The pulses of all channels are centered in the period, which reduces the
+    /// harmonic content of the output and the switching noise in motor drives.
+    CenterAligned,
+}
+
+/// PWM peripheral with a configurable counter mode.
+pub trait SetPwmMode: ErrorType {
+    /// Set the counter mode.
+    ///
+    /// For the same timer period, the frequency seen at the output in [`PwmCountMode::CenterAligned`]
+    /// mode is half the frequency in [`PwmCountMode::EdgeAligned`] mode, since the counter
+    /// has to count both up and down.
+    ///
+    /// Switching modes while the output is active may briefly glitch the output.
+    fn set_count_mode(&mut self, mode: PwmCountMode) -> Result<(), Self::Error>;
+}
+
+impl<T: SetPwmMode + ?Sized> SetPwmMode for &mut T {
+    #[inline]
+    fn set_count_mode(&mut self, mode: PwmCountMode) -> Result<(), Self::Error> {
+        T::set_count_mode(self, mode)
+    }
+}
+
+/// PWM channel with a configurable output polarity.
+///
+/// Inverting the polarity in hardware allows driving active-low loads, such as LEDs connected
+/// to the supply, without inverting the duty cycle in software.
+pub trait SetPwmPolarity: ErrorType {
+    /// Set the output polarity.
+    ///
+    /// If `active_high` is `true` (the default), the output is high during the duty cycle.
+    /// Otherwise the output is inverted: a duty cycle of [`max_duty_cycle`] produces an output
+    /// that is always low, and a duty cycle of 0 an output that is always high.
+    ///
+    /// The polarity change takes effect immediately, without resetting the period.
+    ///
+    /// [`max_duty_cycle`]: SetDutyCycle::max_duty_cycle
+    fn set_polarity(&mut self, active_high: bool) -> Result<(), Self::Error>;
+}
+
+impl<T: SetPwmPolarity + ?Sized> SetPwmPolarity for &mut T {
+    #[inline]
+    fn set_polarity(&mut self, active_high: bool) -> Result<(), Self::Error> {
+        T::set_polarity(self, active_high)
+    }
+}
+
+/// PWM peripheral with a hardware fault input.
+///
+/// Motor control PWM peripherals typically have a fault input (often called "break" input)
+/// which immediately disables all PWM outputs in hardware when asserted, without software
+/// intervention. The outputs stay disabled until the fault is cleared.
+pub trait PwmFaultProtection: ErrorType {
+    /// Enable the fault input, with the given polarity.
+    ///
+    /// If `active_high` is `true`, a fault is signaled when the input is high, otherwise when it's low.
+    fn enable_fault_input(&mut self, active_high: bool) -> Result<(), Self::Error>;
+
+    /// Disable the fault input.
+    fn disable_fault_input(&mut self) -> Result<(), Self::Error>;
+
+    /// Returns `true` if a fault has disabled the outputs and hasn't been cleared yet.
+    fn is_fault_active(&mut self) -> Result<bool, Self::Error>;
+
+    /// Clear the fault, re-enabling the outputs.
+    ///
+    /// If the fault input is still asserted, the fault is immediately signaled again.
+    fn clear_fault(&mut self) -> Result<(), Self::Error>;
+}
+
+impl<T: PwmFaultProtection + ?Sized> PwmFaultProtection for &mut T {
+    #[inline]
+    fn enable_fault_input(&mut self, active_high: bool) -> Result<(), Self::Error> {
+        T::enable_fault_input(self, active_high)
+    }
+
+    #[inline]
+    fn disable_fault_input(&mut self) -> Result<(), Self::Error> {
+        T::disable_fault_input(self)
+    }
+
+    #[inline]
+    fn is_fault_active(&mut self) -> Result<bool, Self::Error> {
+        T::is_fault_active(self)
+    }
+
+    #[inline]
+    fn clear_fault(&mut self) -> Result<(), Self::Error> {
+        T::clear_fault(self)
+    }
+}
+
+/// Edge captured by an [`InputCapture`] channel.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub enum CaptureEdge {
+    /// Low to high transition.
+    Rising,
+    /// High to low transition.
+    Falling,
+    /// Any transition.
+    Both,
+}
+
+/// Timer channel measuring an incoming signal (input capture).
+///
+/// This is the inverse of PWM output: the timer captures its counter value on edges of the input
+/// signal, allowing the period and pulse width of the signal to be measured, e.g. to measure the
+/// frequency of a rotary encoder or the duty cycle of a PWM signal.
+///
+/// Measurements block until the required edges have been captured.
+pub trait InputCapture: ErrorType {
+    /// Set the edge captured by [`measure_period_ns`](InputCapture::measure_period_ns).
+    fn set_capture_edge(&mut self, edge: CaptureEdge) -> Result<(), Self::Error>;
+
+    /// Measure the period of the input signal, in nanoseconds.
+    ///
+    /// This waits for two consecutive capture edges, and returns the time between them.
+    /// Periods longer than the timer range return an error of kind [`ErrorKind::Other`].
+    fn measure_period_ns(&mut self) -> Result<u32, Self::Error>;
+
+    /// Measure the pulse width (high time) of the input signal, in nanoseconds.
+    ///
+    /// This waits for a rising edge followed by a falling edge, and returns the time between
+    /// them, regardless of the configured capture edge.
+    fn measure_pulse_width_ns(&mut self) -> Result<u32, Self::Error>;
+}
+
+impl<T: InputCapture + ?Sized> InputCapture for &mut T {
+    #[inline]
+    fn set_capture_edge(&mut self, edge: CaptureEdge) -> Result<(), Self::Error> {
+        T::set_capture_edge(self, edge)
+    }
+
+    #[inline]
+    fn measure_period_ns(&mut self) -> Result<u32, Self::Error> {
+        T::measure_period_ns(self)
+    }
+
+    #[inline]
+    fn measure_pulse_width_ns(&mut self) -> Result<u32, Self::Error> {
+        T::measure_pulse_width_ns(self)
+    }
+}
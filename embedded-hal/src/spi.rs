@@ -264,6 +264,8 @@ pub enum ErrorKind {
     FrameFormat,
     /// An error occurred while asserting or deasserting the Chip Select pin.
     ChipSelectFault,
+    /// The requested operation is not supported by the implementation.
+    Unsupported,
     /// A different error occurred. The original error may contain more information.
     Other,
 }
@@ -294,6 +296,10 @@ impl core::fmt::Display for ErrorKind {
                 f,
                 "An error occurred while asserting or deasserting the Chip Select pin"
             ),
+            Self::Unsupported => write!(
+                f,
+                "The requested operation is not supported by the implementation"
+            ),
             Self::Other => write!(
                 f,
                 "A different error occurred. The original error may contain more information"
@@ -337,6 +343,12 @@ pub enum Operation<'a, Word: 'static> {
     /// Equivalent to [`SpiBus::transfer_in_place`].
     TransferInPlace(&'a mut [Word]),
     /// Delay for at least the specified number of nanoseconds.
+    ///
+    /// CS stays asserted during the delay. Implementations must wait for all previous operations
+    /// to complete (e.g. by calling [`SpiBus::flush`]) before starting the delay, so the delay
+    /// is measured from the end of the previous operation on the bus.
+    ///
+    /// `DelayNs(0)` is a no-op.
     DelayNs(u32),
 }
 
@@ -403,6 +415,32 @@ pub trait SpiDevice<Word: Copy + 'static = u8>: ErrorType {
     fn transfer_in_place(&mut self, buf: &mut [Word]) -> Result<(), Self::Error> {
         self.transaction(&mut [Operation::TransferInPlace(buf)])
     }
+
+    /// Do a read within a transaction, sending `fill` on MOSI for every word read.
+    ///
+    /// Unlike [`read`](SpiDevice::read), where the word sent on MOSI is implementation-defined, this
+    /// guarantees the value sent. `buf` is filled with `fill`, then used for an in-place transfer,
+    /// so no separate write buffer is needed.
+    ///
+    /// This is a convenience method equivalent to `buf.fill(fill); device.transfer_in_place(buf)`.
+    ///
+    /// See also: [`SpiDevice::transfer_in_place`]
+    #[inline]
+    fn read_with_fill(&mut self, buf: &mut [Word], fill: Word) -> Result<(), Self::Error> {
+        buf.fill(fill);
+        self.transfer_in_place(buf)
+    }
+
+    /// Do a read within a transaction, sending `Word::default()` (zero for integers) on MOSI for every word read.
+    ///
+    /// This is a convenience method equivalent to `device.read_with_fill(buf, Word::default())`.
+    #[inline]
+    fn read_zeroed(&mut self, buf: &mut [Word]) -> Result<(), Self::Error>
+    where
+        Word: Default,
+    {
+        self.read_with_fill(buf, Word::default())
+    }
 }
 
 impl<Word: Copy + 'static, T: SpiDevice<Word> + ?Sized> SpiDevice<Word> for &mut T {
@@ -430,6 +468,19 @@ impl<Word: Copy + 'static, T: SpiDevice<Word> + ?Sized> SpiDevice<Word> for &mut
     fn transfer_in_place(&mut self, buf: &mut [Word]) -> Result<(), Self::Error> {
         T::transfer_in_place(self, buf)
     }
+
+    #[inline]
+    fn read_with_fill(&mut self, buf: &mut [Word], fill: Word) -> Result<(), Self::Error> {
+        T::read_with_fill(self, buf, fill)
+    }
+
+    #[inline]
+    fn read_zeroed(&mut self, buf: &mut [Word]) -> Result<(), Self::Error>
+    where
+        Word: Default,
+    {
+        T::read_zeroed(self, buf)
+    }
 }
 
 /// SPI bus.
@@ -466,6 +517,33 @@ pub trait SpiBus<Word: Copy + 'static = u8>: ErrorType {
     /// complete. See the [module-level documentation](self) for details.
     fn transfer(&mut self, read: &mut [Word], write: &[Word]) -> Result<(), Self::Error>;
 
+    /// Write and read simultaneously, like [`transfer`](SpiBus::transfer), for at most `n` words.
+    ///
+    /// The transfer runs for at most `min(n, max(read.len(), write.len()))` words, and returns the
+    /// number of words actually transferred. Implementations may stop early without error, e.g.
+    /// when the slave signals it has no more data by deasserting a data-ready line.
+    ///
+    /// The default implementation never stops early: it calls [`transfer`](SpiBus::transfer)
+    /// with the buffers truncated to `n` words.
+    ///
+    /// There is no corresponding [`Operation`]: within an [`SpiDevice`] transaction, truncate the
+    /// buffers and use [`Operation::Transfer`] instead.
+    ///
+    /// Implementations are allowed to return before the operation is
+    /// complete. See the [module-level documentation](self) for details.
+    fn transfer_n(
+        &mut self,
+        read: &mut [Word],
+        write: &[Word],
+        n: usize,
+    ) -> Result<usize, Self::Error> {
+        let n = n.min(read.len().max(write.len()));
+        let read_len = n.min(read.len());
+        let write_len = n.min(write.len());
+        self.transfer(&mut read[..read_len], &write[..write_len])?;
+        Ok(n)
+    }
+
     /// Write and read simultaneously. The contents of `words` are
     /// written to the slave, and the received words are stored into the same
     /// `words` buffer, overwriting it.
@@ -478,6 +556,25 @@ pub trait SpiBus<Word: Copy + 'static = u8>: ErrorType {
     ///
     /// See the [module-level documentation](self) for important usage information.
     fn flush(&mut self) -> Result<(), Self::Error>;
+
+    /// Wait until all operations have completed, then delay for at least `ns` nanoseconds.
+    ///
+    /// This allows expressing delays between operations, such as inter-byte delays, at the bus level.
+    /// `delay_ns(0)` must be a no-op.
+    ///
+    /// # Default implementation
+    ///
+    /// **The default implementation performs no delay at all**: since a bus doesn't necessarily
+    /// have access to a time source, it only waits for completion with [`flush`](SpiBus::flush),
+    /// and returns immediately. Implementations able to delay must override it. Drivers requiring
+    /// an actual delay should use [`SpiDevice`] with [`Operation::DelayNs`] instead, unless they
+    /// know the bus implements this method.
+    fn delay_ns(&mut self, ns: u32) -> Result<(), Self::Error> {
+        if ns != 0 {
+            self.flush()?;
+        }
+        Ok(())
+    }
 }
 
 impl<T: SpiBus<Word> + ?Sized, Word: Copy + 'static> SpiBus<Word> for &mut T {
@@ -496,6 +593,16 @@ impl<T: SpiBus<Word> + ?Sized, Word: Copy + 'static> SpiBus<Word> for &mut T {
         T::transfer(self, read, write)
     }
 
+    #[inline]
+    fn transfer_n(
+        &mut self,
+        read: &mut [Word],
+        write: &[Word],
+        n: usize,
+    ) -> Result<usize, Self::Error> {
+        T::transfer_n(self, read, write, n)
+    }
+
     #[inline]
     fn transfer_in_place(&mut self, words: &mut [Word]) -> Result<(), Self::Error> {
         T::transfer_in_place(self, words)
@@ -505,4 +612,245 @@ impl<T: SpiBus<Word> + ?Sized, Word: Copy + 'static> SpiBus<Word> for &mut T {
     fn flush(&mut self) -> Result<(), Self::Error> {
         T::flush(self)
     }
+
+    #[inline]
+    fn delay_ns(&mut self, ns: u32) -> Result<(), Self::Error> {
+        T::delay_ns(self, ns)
+    }
+}
+
+/// SPI bus with dual mode support.
+///
+/// In dual mode, MOSI and MISO are both used as a bidirectional data bus, transferring two bits
+/// per SCK cycle. This doubles the throughput, but only allows either reading or writing at a time.
+///
+/// Not all MCU SPI peripherals support this mode. It is typically found in peripherals intended
+/// for external flash memories.
+///
+/// The same semantics as [`SpiBus`] apply: operations may return before the data is actually
+/// transferred, use [`SpiBus::flush`] to wait for completion.
+///
+/// Dual mode is only available at the bus level: there is no corresponding [`Operation`], so
+/// drivers needing it take an `SpiDualMode` bus and manage CS themselves.
+pub trait SpiDualMode<Word: Copy + 'static = u8>: SpiBus<Word> {
+    /// Read `words` from the slave, using both MOSI and MISO as data lines.
+    fn read_dual(&mut self, words: &mut [Word]) -> Result<(), Self::Error>;
+
+    /// Write `words` to the slave, using both MOSI and MISO as data lines.
+    fn write_dual(&mut self, words: &[Word]) -> Result<(), Self::Error>;
+}
+
+impl<T: SpiDualMode<Word> + ?Sized, Word: Copy + 'static> SpiDualMode<Word> for &mut T {
+    #[inline]
+    fn read_dual(&mut self, words: &mut [Word]) -> Result<(), Self::Error> {
+        T::read_dual(self, words)
+    }
+
+    #[inline]
+    fn write_dual(&mut self, words: &[Word]) -> Result<(), Self::Error> {
+        T::write_dual(self, words)
+    }
+}
+
+/// SPI bus whose MOSI level during reads can be configured.
+///
+/// [`SpiBus::read`] sends implementation-defined words on MOSI. Some devices, such as SPI ADCs,
+/// interpret MOSI during reads, so it must be driven to a known level.
+pub trait ConfigurableSpiBus<Word: Copy + 'static = u8>: SpiBus<Word> {
+    /// Set the level MOSI is held at during reads: high (`true`) or low (`false`).
+    ///
+    /// This affects all subsequent [`SpiBus::read`] operations, and the words sent after the end
+    /// of the write buffer in [`SpiBus::transfer`].
+    fn set_mosi_idle_level(&mut self, level: bool) -> Result<(), Self::Error>;
+
+    /// Read `buf` from the slave, sending `fill` on MOSI for every word read.
+    ///
+    /// This is the bus-level equivalent of [`SpiDevice::read_with_fill`], and overrides the level
+    /// set by [`set_mosi_idle_level`](ConfigurableSpiBus::set_mosi_idle_level) for this operation
+    /// only.
+    ///
+    /// The default implementation fills `buf` with `fill`, then does an in-place transfer.
+    #[inline]
+    fn read_with_fill(&mut self, buf: &mut [Word], fill: Word) -> Result<(), Self::Error> {
+        buf.fill(fill);
+        self.transfer_in_place(buf)
+    }
+}
+
+impl<T: ConfigurableSpiBus<Word> + ?Sized, Word: Copy + 'static> ConfigurableSpiBus<Word>
+    for &mut T
+{
+    #[inline]
+    fn set_mosi_idle_level(&mut self, level: bool) -> Result<(), Self::Error> {
+        T::set_mosi_idle_level(self, level)
+    }
+
+    #[inline]
+    fn read_with_fill(&mut self, buf: &mut [Word], fill: Word) -> Result<(), Self::Error> {
+        T::read_with_fill(self, buf, fill)
+    }
+}
+
+/// SPI bus whose mode and frequency can be changed at runtime.
+///
+/// This allows talking to devices with different requirements on the same bus, or switching to a
+/// higher frequency once a device is initialized. Changes apply to the following operations:
+/// implementations must wait for previous operations to complete (e.g. by calling
+/// [`SpiBus::flush`]) before changing the configuration.
+///
+/// Implementations that can't apply a configuration at runtime (e.g. because it requires
+/// reinitializing the peripheral) return an error of kind [`ErrorKind::Unsupported`].
+pub trait SetSpiConfig: ErrorType {
+    /// Set the SPI mode used by the following operations.
+    fn set_mode(&mut self, mode: Mode) -> Result<(), Self::Error>;
+
+    /// Set the SCK frequency, in Hz, used by the following operations.
+    ///
+    /// The frequency actually configured may be lower than requested, but never higher.
+    fn set_frequency_hz(&mut self, hz: u32) -> Result<(), Self::Error>;
+}
+
+impl<T: SetSpiConfig + ?Sized> SetSpiConfig for &mut T {
+    #[inline]
+    fn set_mode(&mut self, mode: Mode) -> Result<(), Self::Error> {
+        T::set_mode(self, mode)
+    }
+
+    #[inline]
+    fn set_frequency_hz(&mut self, hz: u32) -> Result<(), Self::Error> {
+        T::set_frequency_hz(self, hz)
+    }
+}
+
+/// SPI bus with an internal loopback mode.
+///
+/// In loopback mode, MOSI is internally connected to MISO, so the words read are exactly the
+/// words written, e.g. [`SpiBus::transfer_in_place`] leaves the buffer unchanged. This is useful
+/// to validate the peripheral during manufacturing tests or integration debugging.
+///
+/// See also `test_utils::spi_loopback_test`, available with the `test-utils` Cargo feature.
+pub trait SpiLoopback: ErrorType {
+    /// Enable loopback mode.
+    fn enable_loopback(&mut self) -> Result<(), Self::Error>;
+
+    /// Disable loopback mode.
+    fn disable_loopback(&mut self) -> Result<(), Self::Error>;
+}
+
+impl<T: SpiLoopback + ?Sized> SpiLoopback for &mut T {
+    #[inline]
+    fn enable_loopback(&mut self) -> Result<(), Self::Error> {
+        T::enable_loopback(self)
+    }
+
+    #[inline]
+    fn disable_loopback(&mut self) -> Result<(), Self::Error> {
+        T::disable_loopback(self)
+    }
+}
+
+/// Management of the NSS (hardware slave select) pin of an SPI peripheral.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub enum NssMode {
+    /// The peripheral doesn't use its NSS pin: CS is driven by software, with a GPIO pin.
+    ///
+    /// This is the mode expected by [`SpiDevice`] implementations driving a CS
+    /// [`OutputPin`](crate::digital::OutputPin).
+    SoftwareCs,
+    /// The peripheral drives its NSS pin as an output, asserting CS automatically while
+    /// transferring.
+    HardwareOutput,
+    /// The peripheral uses its NSS pin as an input, to be selected by an external master.
+    HardwareInput,
+}
+
+/// SPI peripheral whose NSS (hardware slave select) pin management can be configured.
+///
+/// Many MCUs, such as STM32s, can have the SPI peripheral drive CS itself. In
+/// [`NssMode::HardwareOutput`] mode, a [`SpiDevice`] built on top of the bus must not drive CS
+/// itself: it should be given a CS pin whose `set_low` and `set_high` are no-ops. Note that
+/// peripherals may deassert CS between words or at the end of each operation, so it's up to the
+/// HAL to document whether the resulting device complies with the [`SpiDevice`] contract.
+pub trait ConfigurableNss: ErrorType {
+    /// Set the NSS pin management mode.
+    fn set_nss_mode(&mut self, mode: NssMode) -> Result<(), Self::Error>;
+}
+
+impl<T: ConfigurableNss + ?Sized> ConfigurableNss for &mut T {
+    #[inline]
+    fn set_nss_mode(&mut self, mode: NssMode) -> Result<(), Self::Error> {
+        T::set_nss_mode(self, mode)
+    }
+}
+
+/// SPI bus with interrupt-driven (or DMA) transfers.
+///
+/// Unlike [`SpiBus::transfer`], [`start_transfer`](SpiInterruptDriven::start_transfer) only starts
+/// the transfer and returns immediately. Completion is then polled with
+/// [`is_transfer_complete`](SpiInterruptDriven::is_transfer_complete), typically from an interrupt
+/// handler, and the buffers are given back by
+/// [`take_buffers`](SpiInterruptDriven::take_buffers).
+///
+/// Since the transfer continues after `start_transfer` returns, the buffers must be `'static`:
+/// the peripheral keeps using them until the transfer completes or is aborted.
+///
+/// For async code, `embedded_hal_async::spi::SpiBus` is usually more convenient.
+pub trait SpiInterruptDriven<Word: Copy + 'static = u8>: ErrorType {
+    /// Start a transfer, writing `tx` and reading into `rx`.
+    ///
+    /// As with [`SpiBus::transfer`], `tx` and `rx` can have different lengths: the transfer is
+    /// as long as the longest one, extra incoming words are discarded and extra outgoing words
+    /// are implementation-defined.
+    ///
+    /// Returns an error if a transfer is already in progress.
+    fn start_transfer(
+        &mut self,
+        tx: &'static [Word],
+        rx: &'static mut [Word],
+    ) -> Result<(), Self::Error>;
+
+    /// Returns `true` if the transfer started by
+    /// [`start_transfer`](SpiInterruptDriven::start_transfer) has completed.
+    ///
+    /// Returns `true` if no transfer was started.
+    fn is_transfer_complete(&mut self) -> Result<bool, Self::Error>;
+
+    /// Abort the transfer in progress, if any.
+    ///
+    /// The content of the read buffer is unspecified after an aborted transfer.
+    fn abort_transfer(&mut self) -> Result<(), Self::Error>;
+
+    /// Take back the buffers of the last completed or aborted transfer.
+    ///
+    /// Returns `None` if no transfer was started, or if it's still in progress.
+    fn take_buffers(&mut self) -> Option<(&'static [Word], &'static mut [Word])>;
+}
+
+impl<T: SpiInterruptDriven<Word> + ?Sized, Word: Copy + 'static> SpiInterruptDriven<Word>
+    for &mut T
+{
+    #[inline]
+    fn start_transfer(
+        &mut self,
+        tx: &'static [Word],
+        rx: &'static mut [Word],
+    ) -> Result<(), Self::Error> {
+        T::start_transfer(self, tx, rx)
+    }
+
+    #[inline]
+    fn is_transfer_complete(&mut self) -> Result<bool, Self::Error> {
+        T::is_transfer_complete(self)
+    }
+
+    #[inline]
+    fn abort_transfer(&mut self) -> Result<(), Self::Error> {
+        T::abort_transfer(self)
+    }
+
+    #[inline]
+    fn take_buffers(&mut self) -> Option<(&'static [Word], &'static mut [Word])> {
+        T::take_buffers(self)
+    }
 }
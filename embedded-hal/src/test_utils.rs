@@ -0,0 +1,83 @@
+//! Utilities to validate hardware implementing the `embedded-hal` traits.
+//!
+//! This module is only available with the `test-utils` Cargo feature.
+
+#[cfg(feature = "defmt-03")]
+use crate::defmt;
+use crate::digital::StatefulOutputPin;
+use crate::spi::{Error, ErrorKind, SpiBus, SpiLoopback};
+
+/// Pattern transferred by [`spi_loopback_test`], exercising all bits in both states.
+const SPI_LOOPBACK_PATTERN: [u8; 8] = [0x00, 0xFF, 0xAA, 0x55, 0x01, 0x80, 0x0F, 0xF0];
+
+/// Error returned by [`spi_loopback_test`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub enum SpiLoopbackError<E> {
+    /// The words read don't match the words written.
+    Mismatch,
+    /// Error returned by the SPI bus.
+    Spi(E),
+}
+
+impl<E: Error> Error for SpiLoopbackError<E> {
+    #[inline]
+    fn kind(&self) -> ErrorKind {
+        match self {
+            Self::Mismatch => ErrorKind::Other,
+            Self::Spi(e) => e.kind(),
+        }
+    }
+}
+
+impl<E: core::fmt::Display> core::fmt::Display for SpiLoopbackError<E> {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Mismatch => write!(f, "SPI loopback data mismatch"),
+            Self::Spi(e) => e.fmt(f),
+        }
+    }
+}
+
+impl<E: core::fmt::Debug + core::fmt::Display> core::error::Error for SpiLoopbackError<E> {}
+
+/// Checks that an SPI bus works, using its loopback mode.
+///
+/// This enables loopback mode, transfers a known pattern, then disables loopback mode.
+/// Returns [`SpiLoopbackError::Mismatch`] if the words read don't match the words written.
+///
+/// Loopback mode is disabled even if the transfer fails, in which case the transfer error is
+/// returned.
+pub fn spi_loopback_test<S: SpiBus + SpiLoopback>(
+    spi: &mut S,
+) -> Result<(), SpiLoopbackError<S::Error>> {
+    let mut buf = SPI_LOOPBACK_PATTERN;
+
+    spi.enable_loopback().map_err(SpiLoopbackError::Spi)?;
+    let res = spi
+        .transfer_in_place(&mut buf)
+        .and_then(|()| SpiBus::flush(spi));
+    let disable_res = spi.disable_loopback();
+    res.map_err(SpiLoopbackError::Spi)?;
+    disable_res.map_err(SpiLoopbackError::Spi)?;
+
+    if buf != SPI_LOOPBACK_PATTERN {
+        return Err(SpiLoopbackError::Mismatch);
+    }
+    Ok(())
+}
+
+/// Toggles `pin` `n` times, as fast as possible.
+///
+/// This is useful to measure the toggle rate achievable through the HAL, and the overhead of the
+/// abstraction, with an oscilloscope or a logic analyzer: the signal has a period of two
+/// toggles. As `embedded-hal` has no way to read the time, the timing can't be measured here.
+///
+/// Stops at the first error.
+pub fn toggle_n_times<P: StatefulOutputPin>(pin: &mut P, n: u32) -> Result<(), P::Error> {
+    for _ in 0..n {
+        pin.toggle()?;
+    }
+    Ok(())
+}
@@ -0,0 +1,94 @@
+//! Adapters for [`arrayvec`](https://docs.rs/arrayvec) containers.
+
+use embedded_io::SliceWriteError;
+
+/// Adapter for `arrayvec::ArrayVec<u8, N>`.
+///
+/// This implements [`embedded_io::Write`] by appending bytes to the vector, and
+/// [`embedded_io::Read`] by removing bytes from the front of it, like a FIFO.
+///
+/// When the vector is full, writes are short, and eventually fail with an error of kind
+/// [`ErrorKind::WriteZero`](embedded_io::ErrorKind::WriteZero).
+///
+/// ```
+/// use embedded_io::{Read, Write};
+/// use embedded_io_adapters::arrayvec::FromArrayVec;
+///
+/// let mut fifo = FromArrayVec::new(arrayvec::ArrayVec::<u8, 8>::new());
+/// fifo.write_all(b"hello").unwrap();
+///
+/// let mut buf = [0; 2];
+/// fifo.read_exact(&mut buf).unwrap();
+/// assert_eq!(&buf, b"he");
+/// assert_eq!(fifo.inner().as_slice(), b"llo");
+/// ```
+#[derive(Clone, Default, PartialEq, Debug)]
+pub struct FromArrayVec<T: ?Sized> {
+    inner: T,
+}
+
+impl<T> FromArrayVec<T> {
+    /// Create a new adapter.
+    pub fn new(inner: T) -> Self {
+        Self { inner }
+    }
+
+    /// Consume the adapter, returning the inner object.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+impl<T: ?Sized> FromArrayVec<T> {
+    /// Borrow the inner object.
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+
+    /// Mutably borrow the inner object.
+    pub fn inner_mut(&mut self) -> &mut T {
+        &mut self.inner
+    }
+}
+
+impl<const N: usize> embedded_io::ErrorType for FromArrayVec<arrayvec::ArrayVec<u8, N>> {
+    type Error = SliceWriteError;
+}
+
+impl<const N: usize> embedded_io::Read for FromArrayVec<arrayvec::ArrayVec<u8, N>> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        let amt = core::cmp::min(buf.len(), self.inner.len());
+        buf[..amt].copy_from_slice(&self.inner[..amt]);
+        self.inner.drain(..amt);
+        Ok(amt)
+    }
+}
+
+impl<const N: usize> embedded_io::ReadReady for FromArrayVec<arrayvec::ArrayVec<u8, N>> {
+    fn read_ready(&mut self) -> Result<bool, Self::Error> {
+        Ok(true)
+    }
+}
+
+impl<const N: usize> embedded_io::Write for FromArrayVec<arrayvec::ArrayVec<u8, N>> {
+    fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+        let amt = core::cmp::min(buf.len(), self.inner.remaining_capacity());
+        if !buf.is_empty() && amt == 0 {
+            return Err(SliceWriteError::Full);
+        }
+        // Can't fail, `amt` bytes fit in the remaining capacity.
+        let _ = self.inner.try_extend_from_slice(&buf[..amt]);
+        Ok(amt)
+    }
+
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+/// Writes never block: when the vector is full, they return an error instead.
+impl<const N: usize> embedded_io::WriteReady for FromArrayVec<arrayvec::ArrayVec<u8, N>> {
+    fn write_ready(&mut self) -> Result<bool, Self::Error> {
+        Ok(true)
+    }
+}
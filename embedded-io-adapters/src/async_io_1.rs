@@ -0,0 +1,72 @@
+//! Adapters from `async-io` streams, as used by the `smol` runtime.
+
+// MSRV is 1.60 if you don't enable async, 1.80 if you do.
+// Cargo.toml has 1.60, which makes Clippy complain that `poll_fn` was introduced
+// in 1.64. So, just silence it for this file.
+#![allow(clippy::incompatible_msrv)]
+
+use core::future::poll_fn;
+use core::pin::Pin;
+
+use async_io::Async;
+use futures_io::{AsyncRead, AsyncWrite};
+
+/// Adapter from [`async_io::Async`] streams.
+///
+/// This wraps an `Async<T>`, e.g. an `Async<TcpStream>` from `smol`, implementing the
+/// `embedded-io-async` traits for it. Errors are `std::io::Error`, as for the other `std`-based
+/// adapters.
+pub struct FromAsyncIo<T> {
+    inner: Async<T>,
+}
+
+impl<T> FromAsyncIo<T> {
+    /// Create a new adapter.
+    pub fn new(inner: Async<T>) -> Self {
+        Self { inner }
+    }
+
+    /// Consume the adapter, returning the inner object.
+    pub fn into_inner(self) -> Async<T> {
+        self.inner
+    }
+
+    /// Borrow the inner object.
+    pub fn inner(&self) -> &Async<T> {
+        &self.inner
+    }
+
+    /// Mutably borrow the inner object.
+    pub fn inner_mut(&mut self) -> &mut Async<T> {
+        &mut self.inner
+    }
+}
+
+impl<T> embedded_io::ErrorType for FromAsyncIo<T> {
+    type Error = std::io::Error;
+}
+
+impl<T: std::io::Read> embedded_io_async::Read for FromAsyncIo<T> {
+    async fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        poll_fn(|cx| Pin::new(&mut self.inner).poll_read(cx, buf)).await
+    }
+}
+
+impl<T: std::io::Write> embedded_io_async::Write for FromAsyncIo<T> {
+    async fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+        match poll_fn(|cx| Pin::new(&mut self.inner).poll_write(cx, buf)).await {
+            Ok(0) if !buf.is_empty() => Err(std::io::ErrorKind::WriteZero.into()),
+            Ok(n) => Ok(n),
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn flush(&mut self) -> Result<(), Self::Error> {
+        poll_fn(|cx| Pin::new(&mut self.inner).poll_flush(cx)).await
+    }
+}
+
+// TODO: ToAsyncIo.
+// Same as for `ToFutures`, it's tricky because `futures-io` traits are "stateless", while
+// we're "stateful" (we return futures that borrow Self and get polled for the duration
+// of the operation.)
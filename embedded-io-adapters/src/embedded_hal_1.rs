@@ -0,0 +1,226 @@
+//! Adapters using `embedded-hal` traits.
+
+use embedded_hal::delay::DelayNs;
+use embedded_io::{Error, ErrorKind, ErrorType, Read, ReadReady};
+
+/// Interval between two polls of the serial port by [`SerialWithTimeout::read_timeout`].
+const POLL_INTERVAL_US: u32 = 100;
+
+/// Error returned by [`SerialWithTimeout::read_timeout`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum SerialTimeoutError<E> {
+    /// The timeout expired before the buffer was filled, after reading the given number of bytes.
+    ///
+    /// The number of bytes read may be zero, if nothing was received.
+    Timeout(usize),
+    /// Error returned by the inner serial port.
+    Other(E),
+}
+
+impl<E: Error> Error for SerialTimeoutError<E> {
+    fn kind(&self) -> ErrorKind {
+        match self {
+            Self::Timeout(_) => ErrorKind::TimedOut,
+            Self::Other(e) => e.kind(),
+        }
+    }
+}
+
+impl<E: core::fmt::Debug> core::fmt::Display for SerialTimeoutError<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+impl<E: core::fmt::Debug> core::error::Error for SerialTimeoutError<E> {}
+
+/// Serial port reader with support for timeouts.
+///
+/// Serial protocols without idle-line detection often rely on timeouts to find packet
+/// boundaries. [`read_timeout`](SerialWithTimeout::read_timeout) reads bytes until the buffer
+/// is filled, the end of file is reached, or the timeout expires, polling the serial port with [`ReadReady`] and waiting with
+/// the `delay` between polls.
+///
+/// `Read` and `ReadReady` are forwarded to the inner serial port, without timeout.
+#[derive(Clone, Default, PartialEq, Debug)]
+pub struct SerialWithTimeout<S, D> {
+    inner: S,
+    delay: D,
+}
+
+impl<S, D> SerialWithTimeout<S, D> {
+    /// Create a new adapter.
+    pub fn new(inner: S, delay: D) -> Self {
+        Self { inner, delay }
+    }
+
+    /// Consume the adapter, returning the inner serial port and delay.
+    pub fn into_inner(self) -> (S, D) {
+        (self.inner, self.delay)
+    }
+
+    /// Borrow the inner serial port.
+    pub fn inner(&self) -> &S {
+        &self.inner
+    }
+
+    /// Mutably borrow the inner serial port.
+    pub fn inner_mut(&mut self) -> &mut S {
+        &mut self.inner
+    }
+}
+
+impl<S: Read + ReadReady, D: DelayNs> SerialWithTimeout<S, D> {
+    /// Read bytes into `buf` until it is filled, or until `timeout_ms` milliseconds elapse.
+    ///
+    /// Returns the number of bytes read, which is `buf.len()` unless the end of file was reached
+    /// (the inner serial port returned `Ok(0)`), in which case it may be anything down to zero.
+    ///
+    /// If the timeout expires first, [`SerialTimeoutError::Timeout`] is returned with the number
+    /// of bytes read at the beginning of `buf`, possibly zero.
+    ///
+    /// Only the time spent waiting between polls is counted, so the actual timeout may be
+    /// slightly longer.
+    pub fn read_timeout(
+        &mut self,
+        buf: &mut [u8],
+        timeout_ms: u32,
+    ) -> Result<usize, SerialTimeoutError<S::Error>> {
+        let mut n = 0;
+        let mut remaining_us = u64::from(timeout_ms) * 1000;
+        while n < buf.len() {
+            if self.inner.read_ready().map_err(SerialTimeoutError::Other)? {
+                match self
+                    .inner
+                    .read(&mut buf[n..])
+                    .map_err(SerialTimeoutError::Other)?
+                {
+                    0 => break,
+                    read => n += read,
+                }
+            } else if remaining_us == 0 {
+                return Err(SerialTimeoutError::Timeout(n));
+            } else {
+                let us = remaining_us.min(u64::from(POLL_INTERVAL_US));
+                self.delay.delay_us(us as u32);
+                remaining_us -= us;
+            }
+        }
+        Ok(n)
+    }
+}
+
+impl<S: ErrorType, D> ErrorType for SerialWithTimeout<S, D> {
+    type Error = S::Error;
+}
+
+impl<S: Read, D> Read for SerialWithTimeout<S, D> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        self.inner.read(buf)
+    }
+}
+
+impl<S: ReadReady, D> ReadReady for SerialWithTimeout<S, D> {
+    fn read_ready(&mut self) -> Result<bool, Self::Error> {
+        self.inner.read_ready()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::convert::Infallible;
+
+    /// Serial port replaying a script: each entry is either a chunk of data returned by one read,
+    /// or `None` for a poll with no data available. Once the script ends, the port is at end of
+    /// file if `eof` is set, and idle otherwise.
+    struct MockSerial {
+        script: &'static [Option<&'static [u8]>],
+        eof: bool,
+    }
+
+    impl ErrorType for MockSerial {
+        type Error = Infallible;
+    }
+
+    impl ReadReady for MockSerial {
+        fn read_ready(&mut self) -> Result<bool, Self::Error> {
+            match self.script.split_first() {
+                Some((None, rest)) => {
+                    self.script = rest;
+                    Ok(false)
+                }
+                Some((Some(_), _)) => Ok(true),
+                None => Ok(self.eof),
+            }
+        }
+    }
+
+    impl Read for MockSerial {
+        fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+            let Some((Some(chunk), rest)) = self.script.split_first() else {
+                return Ok(0);
+            };
+            let n = chunk.len().min(buf.len());
+            buf[..n].copy_from_slice(&chunk[..n]);
+            self.script = rest;
+            Ok(n)
+        }
+    }
+
+    /// Delay recording the total time waited.
+    #[derive(Default)]
+    struct MockDelay(u64);
+
+    impl DelayNs for MockDelay {
+        fn delay_ns(&mut self, ns: u32) {
+            self.0 += u64::from(ns);
+        }
+    }
+
+    fn serial(
+        script: &'static [Option<&'static [u8]>],
+        eof: bool,
+    ) -> SerialWithTimeout<MockSerial, MockDelay> {
+        SerialWithTimeout::new(MockSerial { script, eof }, MockDelay::default())
+    }
+
+    #[test]
+    fn full() {
+        let mut serial = serial(&[Some(b"ab"), None, Some(b"cd")], false);
+        let mut buf = [0; 4];
+        assert_eq!(serial.read_timeout(&mut buf, 1), Ok(4));
+        assert_eq!(&buf, b"abcd");
+    }
+
+    #[test]
+    fn timeout() {
+        let mut serial = serial(&[], false);
+        let mut buf = [0; 4];
+        assert_eq!(
+            serial.read_timeout(&mut buf, 2),
+            Err(SerialTimeoutError::Timeout(0))
+        );
+        assert_eq!(serial.into_inner().1 .0, 2_000_000);
+    }
+
+    #[test]
+    fn partial_read() {
+        let mut serial = serial(&[Some(b"ab"), None, Some(b"c")], false);
+        let mut buf = [0; 4];
+        assert_eq!(
+            serial.read_timeout(&mut buf, 1),
+            Err(SerialTimeoutError::Timeout(3))
+        );
+        assert_eq!(&buf[..3], b"abc");
+    }
+
+    #[test]
+    fn eof() {
+        let mut serial = serial(&[Some(b"ab")], true);
+        let mut buf = [0; 4];
+        assert_eq!(serial.read_timeout(&mut buf, 1), Ok(2));
+        assert_eq!(&buf[..2], b"ab");
+        assert_eq!(serial.read_timeout(&mut buf, 1), Ok(0));
+    }
+}
@@ -0,0 +1,130 @@
+//! Adapters from `embedded-hal-async` serial traits.
+//!
+//! These allow using HALs that only implement the `embedded_hal_async::serial` traits with code
+//! written against `embedded-io-async`.
+
+use embedded_hal_async::serial::{self, ErrorKind as SerialErrorKind};
+use embedded_io_async::ErrorKind;
+
+/// Error returned by the `embedded-hal-async` adapters.
+///
+/// This wraps the error of the inner serial port, implementing [`embedded_io_async::Error`] for it.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct HalAsyncSerialError<E>(pub E);
+
+impl<E: serial::Error> embedded_io_async::Error for HalAsyncSerialError<E> {
+    fn kind(&self) -> ErrorKind {
+        match self.0.kind() {
+            SerialErrorKind::FrameFormat | SerialErrorKind::Parity | SerialErrorKind::Noise => {
+                ErrorKind::InvalidData
+            }
+            SerialErrorKind::InvalidInput => ErrorKind::InvalidInput,
+            _ => ErrorKind::Other,
+        }
+    }
+}
+
+impl<E: serial::Error> core::fmt::Display for HalAsyncSerialError<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:?}", self.0)
+    }
+}
+
+impl<E: serial::Error> core::error::Error for HalAsyncSerialError<E> {}
+
+/// Adapter from `embedded_hal_async::serial::Read<u8>` to `embedded_io_async::Read`.
+///
+/// `embedded_hal_async::serial::Read` can only wait for an exact number of words, so `read`
+/// returns one byte at a time, as soon as it is received.
+#[derive(Clone, Default, PartialEq, Debug)]
+pub struct HalAsyncReadAdapter<T: ?Sized> {
+    inner: T,
+}
+
+impl<T> HalAsyncReadAdapter<T> {
+    /// Create a new adapter.
+    pub fn new(inner: T) -> Self {
+        Self { inner }
+    }
+
+    /// Consume the adapter, returning the inner object.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+impl<T: ?Sized> HalAsyncReadAdapter<T> {
+    /// Borrow the inner object.
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+
+    /// Mutably borrow the inner object.
+    pub fn inner_mut(&mut self) -> &mut T {
+        &mut self.inner
+    }
+}
+
+impl<T: serial::ErrorType + ?Sized> embedded_io_async::ErrorType for HalAsyncReadAdapter<T> {
+    type Error = HalAsyncSerialError<T::Error>;
+}
+
+impl<T: serial::Read<u8> + ?Sized> embedded_io_async::Read for HalAsyncReadAdapter<T> {
+    async fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+        self.inner
+            .read_exact(&mut buf[..1])
+            .await
+            .map_err(HalAsyncSerialError)?;
+        Ok(1)
+    }
+}
+
+/// Adapter from `embedded_hal_async::serial::Write<u8>` to `embedded_io_async::Write`.
+///
+/// `write` hands over the whole buffer to the inner serial port.
+#[derive(Clone, Default, PartialEq, Debug)]
+pub struct HalAsyncWriteAdapter<T: ?Sized> {
+    inner: T,
+}
+
+impl<T> HalAsyncWriteAdapter<T> {
+    /// Create a new adapter.
+    pub fn new(inner: T) -> Self {
+        Self { inner }
+    }
+
+    /// Consume the adapter, returning the inner object.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+impl<T: ?Sized> HalAsyncWriteAdapter<T> {
+    /// Borrow the inner object.
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+
+    /// Mutably borrow the inner object.
+    pub fn inner_mut(&mut self) -> &mut T {
+        &mut self.inner
+    }
+}
+
+impl<T: serial::ErrorType + ?Sized> embedded_io_async::ErrorType for HalAsyncWriteAdapter<T> {
+    type Error = HalAsyncSerialError<T::Error>;
+}
+
+impl<T: serial::Write<u8> + ?Sized> embedded_io_async::Write for HalAsyncWriteAdapter<T> {
+    async fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+        self.inner.write(buf).await.map_err(HalAsyncSerialError)?;
+        Ok(buf.len())
+    }
+
+    async fn flush(&mut self) -> Result<(), Self::Error> {
+        self.inner.flush().await.map_err(HalAsyncSerialError)
+    }
+}
@@ -0,0 +1,304 @@
+//! Adapters from `embedded-hal-nb` serial traits.
+//!
+//! These allow using HALs that only implement the [`nb`](https://docs.rs/nb)-based
+//! `embedded_hal_nb::serial` traits with code written against `embedded-io`.
+
+use embedded_hal_nb::serial::{self, ErrorKind as SerialErrorKind};
+use embedded_io::ErrorKind;
+
+/// Error returned by the `embedded-hal-nb` adapters.
+///
+/// This wraps the error of the inner serial port, implementing [`embedded_io::Error`] for it.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct NbSerialError<E>(pub E);
+
+impl<E: serial::Error> embedded_io::Error for NbSerialError<E> {
+    fn kind(&self) -> ErrorKind {
+        match self.0.kind() {
+            SerialErrorKind::FrameFormat | SerialErrorKind::Parity | SerialErrorKind::Noise => {
+                ErrorKind::InvalidData
+            }
+            SerialErrorKind::InvalidInput => ErrorKind::InvalidInput,
+            _ => ErrorKind::Other,
+        }
+    }
+}
+
+impl<E: serial::Error> core::fmt::Display for NbSerialError<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:?}", self.0)
+    }
+}
+
+impl<E: serial::Error> core::error::Error for NbSerialError<E> {}
+
+/// Adapter from `embedded_hal_nb::serial::Read<u8>` to `embedded_io::Read`.
+///
+/// `read` spins on [`nb::Error::WouldBlock`] until at least one byte is received, then
+/// returns all the bytes that can be read without blocking. An error after the first byte ends
+/// the read early, so the bytes already received are not lost, and is returned by the next call.
+///
+/// `read_ready` reads a byte from the serial port to find out if one is available, and keeps
+/// it for the next `read`.
+#[derive(Clone, Default, PartialEq, Debug)]
+pub struct BlockingNbRead<T: serial::ErrorType + ?Sized> {
+    pending: Option<u8>,
+    pending_error: Option<T::Error>,
+    inner: T,
+}
+
+impl<T: serial::ErrorType> BlockingNbRead<T> {
+    /// Create a new adapter.
+    pub fn new(inner: T) -> Self {
+        Self {
+            pending: None,
+            pending_error: None,
+            inner,
+        }
+    }
+
+    /// Consume the adapter, returning the inner object.
+    ///
+    /// A byte read by `read_ready` but not returned by `read` yet is lost, as is an error not
+    /// returned yet.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+impl<T: serial::ErrorType + ?Sized> BlockingNbRead<T> {
+    /// Borrow the inner object.
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+
+    /// Mutably borrow the inner object.
+    pub fn inner_mut(&mut self) -> &mut T {
+        &mut self.inner
+    }
+}
+
+impl<T: serial::ErrorType + ?Sized> embedded_io::ErrorType for BlockingNbRead<T> {
+    type Error = NbSerialError<T::Error>;
+}
+
+impl<T: serial::Read<u8> + ?Sized> embedded_io::Read for BlockingNbRead<T> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        if let Some(e) = self.pending_error.take() {
+            return Err(NbSerialError(e));
+        }
+        let Some((first, rest)) = buf.split_first_mut() else {
+            return Ok(0);
+        };
+        *first = match self.pending.take() {
+            Some(word) => word,
+            None => nb::block!(self.inner.read()).map_err(NbSerialError)?,
+        };
+
+        let mut n = 1;
+        for b in rest {
+            match self.inner.read() {
+                Ok(word) => *b = word,
+                Err(nb::Error::WouldBlock) => break,
+                Err(nb::Error::Other(e)) => {
+                    // Report the bytes already read, and the error on the next call.
+                    self.pending_error = Some(e);
+                    break;
+                }
+            }
+            n += 1;
+        }
+        Ok(n)
+    }
+}
+
+impl<T: serial::Read<u8> + ?Sized> embedded_io::ReadReady for BlockingNbRead<T> {
+    fn read_ready(&mut self) -> Result<bool, Self::Error> {
+        if self.pending.is_none() && self.pending_error.is_none() {
+            match self.inner.read() {
+                Ok(word) => self.pending = Some(word),
+                Err(nb::Error::WouldBlock) => return Ok(false),
+                Err(nb::Error::Other(e)) => return Err(NbSerialError(e)),
+            }
+        }
+        Ok(true)
+    }
+}
+
+/// Adapter from `embedded_hal_nb::serial::Write<u8>` to `embedded_io::Write`.
+///
+/// `write` spins on [`nb::Error::WouldBlock`] until at least one byte is accepted, then
+/// writes all the bytes that can be written without blocking. An error after the first byte ends
+/// the write early, so the bytes already accepted are reported, and is returned by the next call
+/// to `write` or `flush`. `flush` spins until the inner serial port has been flushed.
+///
+/// `write_ready` returns `true` only once the inner serial port has been flushed, as the `nb`
+/// serial traits can't tell if a word would be accepted without writing it.
+#[derive(Clone, Default, PartialEq, Debug)]
+pub struct BlockingNbWrite<T: serial::ErrorType + ?Sized> {
+    pending_error: Option<T::Error>,
+    inner: T,
+}
+
+impl<T: serial::ErrorType> BlockingNbWrite<T> {
+    /// Create a new adapter.
+    pub fn new(inner: T) -> Self {
+        Self {
+            pending_error: None,
+            inner,
+        }
+    }
+
+    /// Consume the adapter, returning the inner object.
+    ///
+    /// An error not returned yet is lost.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+impl<T: serial::ErrorType + ?Sized> BlockingNbWrite<T> {
+    /// Borrow the inner object.
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+
+    /// Mutably borrow the inner object.
+    pub fn inner_mut(&mut self) -> &mut T {
+        &mut self.inner
+    }
+}
+
+impl<T: serial::ErrorType + ?Sized> embedded_io::ErrorType for BlockingNbWrite<T> {
+    type Error = NbSerialError<T::Error>;
+}
+
+impl<T: serial::Write<u8> + ?Sized> embedded_io::Write for BlockingNbWrite<T> {
+    fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+        if let Some(e) = self.pending_error.take() {
+            return Err(NbSerialError(e));
+        }
+        let Some((first, rest)) = buf.split_first() else {
+            return Ok(0);
+        };
+        nb::block!(self.inner.write(*first)).map_err(NbSerialError)?;
+
+        let mut n = 1;
+        for b in rest {
+            match self.inner.write(*b) {
+                Ok(()) => {}
+                Err(nb::Error::WouldBlock) => break,
+                Err(nb::Error::Other(e)) => {
+                    // Report the bytes already written, and the error on the next call.
+                    self.pending_error = Some(e);
+                    break;
+                }
+            }
+            n += 1;
+        }
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        if let Some(e) = self.pending_error.take() {
+            return Err(NbSerialError(e));
+        }
+        nb::block!(self.inner.flush()).map_err(NbSerialError)
+    }
+}
+
+impl<T: serial::Write<u8> + ?Sized> embedded_io::WriteReady for BlockingNbWrite<T> {
+    fn write_ready(&mut self) -> Result<bool, Self::Error> {
+        if self.pending_error.is_some() {
+            return Ok(true);
+        }
+        match self.inner.flush() {
+            Ok(()) => Ok(true),
+            Err(nb::Error::WouldBlock) => Ok(false),
+            Err(nb::Error::Other(e)) => Err(NbSerialError(e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_io::{Read, Write};
+
+    #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+    struct MockError;
+
+    impl serial::Error for MockError {
+        fn kind(&self) -> SerialErrorKind {
+            SerialErrorKind::Overrun
+        }
+    }
+
+    /// Serial port transferring one byte, then failing once, then transferring bytes again.
+    #[derive(Default)]
+    struct FailOnce {
+        count: usize,
+        written: [u8; 4],
+    }
+
+    impl serial::ErrorType for FailOnce {
+        type Error = MockError;
+    }
+
+    impl serial::Read<u8> for FailOnce {
+        fn read(&mut self) -> nb::Result<u8, MockError> {
+            self.count += 1;
+            match self.count {
+                2 => Err(nb::Error::Other(MockError)),
+                n if n > 4 => Err(nb::Error::WouldBlock),
+                n => Ok(n as u8),
+            }
+        }
+    }
+
+    impl serial::Write<u8> for FailOnce {
+        fn write(&mut self, word: u8) -> nb::Result<(), MockError> {
+            self.count += 1;
+            match self.count {
+                2 => Err(nb::Error::Other(MockError)),
+                n if n > 4 => Err(nb::Error::WouldBlock),
+                n => {
+                    self.written[n - 1] = word;
+                    Ok(())
+                }
+            }
+        }
+
+        fn flush(&mut self) -> nb::Result<(), MockError> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn read_error_after_first_byte() {
+        let mut reader = BlockingNbRead::new(FailOnce::default());
+        let mut buf = [0; 4];
+        assert_eq!(reader.read(&mut buf), Ok(1));
+        assert_eq!(buf[0], 1);
+        assert_eq!(reader.read(&mut buf), Err(NbSerialError(MockError)));
+        assert_eq!(reader.read(&mut buf), Ok(2));
+        assert_eq!(buf[..2], [3, 4]);
+    }
+
+    #[test]
+    fn write_error_after_first_byte() {
+        let mut writer = BlockingNbWrite::new(FailOnce::default());
+        assert_eq!(writer.write(&[10, 11, 12]), Ok(1));
+        assert_eq!(writer.write(&[11, 12]), Err(NbSerialError(MockError)));
+        assert_eq!(writer.write(&[11, 12]), Ok(2));
+        assert_eq!(writer.into_inner().written, [10, 0, 11, 12]);
+    }
+
+    #[test]
+    fn flush_returns_pending_error() {
+        let mut writer = BlockingNbWrite::new(FailOnce::default());
+        assert_eq!(writer.write(&[10, 11]), Ok(1));
+        assert_eq!(writer.flush(), Err(NbSerialError(MockError)));
+        assert_eq!(writer.flush(), Ok(()));
+    }
+}
@@ -0,0 +1,281 @@
+//! Adapters for [`heapless`](https://docs.rs/heapless) containers.
+
+use embedded_io::{ErrorKind, SliceWriteError};
+
+/// Adapter for `heapless` containers.
+///
+/// - `FromHeapless<heapless::Vec<u8, N>>` implements [`embedded_io::Write`] by appending bytes
+///   to the vector, and [`embedded_io::Read`] by removing bytes from the front of it, like a FIFO.
+/// - `FromHeapless<heapless::String<N>>` implements [`embedded_io::Write`] by appending
+///   UTF-8 data to the string.
+///
+/// When the container is full, writes are short, and eventually fail with an error of kind
+/// [`ErrorKind::WriteZero`].
+///
+/// ```
+/// use embedded_io::{Read, Write};
+/// use embedded_io_adapters::heapless::FromHeapless;
+///
+/// let mut fifo = FromHeapless::new(heapless::Vec::<u8, 8>::new());
+/// fifo.write_all(b"hello").unwrap();
+///
+/// let mut buf = [0; 2];
+/// fifo.read_exact(&mut buf).unwrap();
+/// assert_eq!(&buf, b"he");
+/// assert_eq!(fifo.inner(), b"llo");
+/// ```
+#[derive(Clone, Default, PartialEq, Debug)]
+pub struct FromHeapless<T: ?Sized> {
+    inner: T,
+}
+
+impl<T> FromHeapless<T> {
+    /// Create a new adapter.
+    pub fn new(inner: T) -> Self {
+        Self { inner }
+    }
+
+    /// Consume the adapter, returning the inner object.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+impl<T: ?Sized> FromHeapless<T> {
+    /// Borrow the inner object.
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+
+    /// Mutably borrow the inner object.
+    pub fn inner_mut(&mut self) -> &mut T {
+        &mut self.inner
+    }
+}
+
+impl<const N: usize> embedded_io::ErrorType for FromHeapless<heapless::Vec<u8, N>> {
+    type Error = SliceWriteError;
+}
+
+impl<const N: usize> embedded_io::Read for FromHeapless<heapless::Vec<u8, N>> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        let amt = core::cmp::min(buf.len(), self.inner.len());
+        buf[..amt].copy_from_slice(&self.inner[..amt]);
+        self.inner.copy_within(amt.., 0);
+        self.inner.truncate(self.inner.len() - amt);
+        Ok(amt)
+    }
+}
+
+impl<const N: usize> embedded_io::ReadReady for FromHeapless<heapless::Vec<u8, N>> {
+    fn read_ready(&mut self) -> Result<bool, Self::Error> {
+        Ok(true)
+    }
+}
+
+impl<const N: usize> embedded_io::Write for FromHeapless<heapless::Vec<u8, N>> {
+    fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+        let amt = core::cmp::min(buf.len(), N - self.inner.len());
+        if !buf.is_empty() && amt == 0 {
+            return Err(SliceWriteError::Full);
+        }
+        // Can't fail, `amt` bytes fit in the remaining capacity.
+        let _ = self.inner.extend_from_slice(&buf[..amt]);
+        Ok(amt)
+    }
+
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+impl<const N: usize> embedded_io::WriteReady for FromHeapless<heapless::Vec<u8, N>> {
+    fn write_ready(&mut self) -> Result<bool, Self::Error> {
+        Ok(true)
+    }
+}
+
+/// Error returned when writing to a `heapless::String`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum StringWriteError {
+    /// The string is full.
+    Full,
+    /// The data written is not valid UTF-8.
+    InvalidUtf8,
+}
+
+impl embedded_io::Error for StringWriteError {
+    fn kind(&self) -> ErrorKind {
+        match self {
+            Self::Full => ErrorKind::WriteZero,
+            Self::InvalidUtf8 => ErrorKind::InvalidData,
+        }
+    }
+}
+
+impl core::fmt::Display for StringWriteError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+impl core::error::Error for StringWriteError {}
+
+impl<const N: usize> embedded_io::ErrorType for FromHeapless<heapless::String<N>> {
+    type Error = StringWriteError;
+}
+
+/// Writes must consist of complete UTF-8 characters, otherwise an error of kind
+/// [`ErrorKind::InvalidData`] is returned. When the string is almost full, only the
+/// characters that fit are written.
+impl<const N: usize> embedded_io::Write for FromHeapless<heapless::String<N>> {
+    fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+        let amt = core::cmp::min(buf.len(), N - self.inner.len());
+        if !buf.is_empty() && amt == 0 {
+            return Err(StringWriteError::Full);
+        }
+        let s = match core::str::from_utf8(&buf[..amt]) {
+            Ok(s) => s,
+            // A character straddles the capacity limit: only write the characters before it.
+            Err(e) if e.error_len().is_none() && amt < buf.len() => {
+                if e.valid_up_to() == 0 {
+                    return Err(StringWriteError::Full);
+                }
+                // Can't fail, the prefix has been validated.
+                core::str::from_utf8(&buf[..e.valid_up_to()]).unwrap()
+            }
+            Err(_) => return Err(StringWriteError::InvalidUtf8),
+        };
+        // Can't fail, `s` fits in the remaining capacity.
+        let _ = self.inner.push_str(s);
+        Ok(s.len())
+    }
+
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+/// Error returned by [`HeaplessMapWriter`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum MapWriteError {
+    /// The map is full, and the key isn't already in it.
+    Full,
+    /// The key byte couldn't be converted to the key type.
+    InvalidKey,
+    /// The value bytes couldn't be converted to the value type.
+    InvalidValue,
+}
+
+impl embedded_io::Error for MapWriteError {
+    fn kind(&self) -> ErrorKind {
+        match self {
+            Self::Full => ErrorKind::OutOfMemory,
+            Self::InvalidKey | Self::InvalidValue => ErrorKind::InvalidData,
+        }
+    }
+}
+
+impl core::fmt::Display for MapWriteError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+impl core::error::Error for MapWriteError {}
+
+/// Writer decoding key-value records into a `heapless::LinearMap`.
+///
+/// The bytes written are a sequence of type-length-value (TLV) records:
+///
+/// ``` text
+/// +-----+--------+-----------------+
+/// | key | length | value           |
+/// +-----+--------+-----------------+
+///   1 B    1 B     `length` bytes
+/// ```
+///
+/// The key byte is converted with `K::try_from(u8)`, and the value with `V::try_from(&[u8])`.
+/// Each complete record is inserted in the map, replacing the previous value of the key if any.
+/// Records can be split across writes: an incomplete record is kept in an internal buffer of 257
+/// bytes until its remaining bytes are written.
+///
+/// This is convenient to receive configuration dictionaries over a serial link.
+///
+/// If a record can't be decoded or inserted, the write returns an error and the record is dropped.
+/// The bytes of the previous records in the same write have been processed.
+///
+/// ```
+/// use embedded_io::Write;
+/// use embedded_io_adapters::heapless::HeaplessMapWriter;
+/// use heapless::{LinearMap, Vec};
+///
+/// let mut map: LinearMap<u8, Vec<u8, 4>, 8> = LinearMap::new();
+/// let mut writer = HeaplessMapWriter::new(&mut map);
+/// writer.write_all(&[1, 2, 0xAB, 0xCD, 2, 0]).unwrap();
+///
+/// assert_eq!(map.get(&1).unwrap(), &[0xAB, 0xCD]);
+/// assert!(map.get(&2).unwrap().is_empty());
+/// ```
+pub struct HeaplessMapWriter<'a, K, V, const N: usize> {
+    map: &'a mut heapless::LinearMap<K, V, N>,
+    record: [u8; 257],
+    record_len: usize,
+}
+
+impl<'a, K, V, const N: usize> HeaplessMapWriter<'a, K, V, N> {
+    /// Create a new writer inserting records into `map`.
+    pub fn new(map: &'a mut heapless::LinearMap<K, V, N>) -> Self {
+        Self {
+            map,
+            record: [0; 257],
+            record_len: 0,
+        }
+    }
+
+    /// Borrow the map.
+    pub fn map(&self) -> &heapless::LinearMap<K, V, N> {
+        self.map
+    }
+
+    /// Returns `true` if an incomplete record has been written.
+    pub fn has_partial_record(&self) -> bool {
+        self.record_len != 0
+    }
+}
+
+impl<K, V, const N: usize> embedded_io::ErrorType for HeaplessMapWriter<'_, K, V, N> {
+    type Error = MapWriteError;
+}
+
+impl<K, V, const N: usize> embedded_io::Write for HeaplessMapWriter<'_, K, V, N>
+where
+    K: Eq + TryFrom<u8>,
+    V: for<'b> TryFrom<&'b [u8]>,
+{
+    fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+        for &byte in buf {
+            self.record[self.record_len] = byte;
+            self.record_len += 1;
+
+            if self.record_len < 2 || self.record_len < 2 + usize::from(self.record[1]) {
+                continue;
+            }
+            self.record_len = 0;
+
+            let key = K::try_from(self.record[0]).map_err(|_| MapWriteError::InvalidKey)?;
+            let value = V::try_from(&self.record[2..2 + usize::from(self.record[1])])
+                .map_err(|_| MapWriteError::InvalidValue)?;
+            self.map
+                .insert(key, value)
+                .map_err(|_| MapWriteError::Full)?;
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
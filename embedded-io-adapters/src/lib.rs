@@ -16,3 +16,31 @@ pub mod futures_03;
 #[cfg(feature = "tokio-1")]
 #[cfg_attr(docsrs, doc(cfg(feature = "tokio-1")))]
 pub mod tokio_1;
+
+#[cfg(feature = "smoltcp-012")]
+#[cfg_attr(docsrs, doc(cfg(feature = "smoltcp-012")))]
+pub mod smoltcp_012;
+
+#[cfg(feature = "embedded-hal-1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "embedded-hal-1")))]
+pub mod embedded_hal_1;
+
+#[cfg(feature = "embedded-hal-nb-1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "embedded-hal-nb-1")))]
+pub mod embedded_hal_nb_1;
+
+#[cfg(feature = "embedded-hal-async-1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "embedded-hal-async-1")))]
+pub mod embedded_hal_async_1;
+
+#[cfg(feature = "heapless")]
+#[cfg_attr(docsrs, doc(cfg(feature = "heapless")))]
+pub mod heapless;
+
+#[cfg(feature = "arrayvec")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arrayvec")))]
+pub mod arrayvec;
+
+#[cfg(feature = "async-io-1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "async-io-1")))]
+pub mod async_io_1;
@@ -0,0 +1,173 @@
+//! Adapters from `smoltcp` TCP sockets.
+
+// MSRV is 1.60 if you don't enable async, 1.80 if you do.
+// Cargo.toml has 1.60, which makes Clippy complain that `poll_fn` was introduced
+// in 1.64. So, just silence it for this file.
+#![allow(clippy::incompatible_msrv)]
+
+use core::cell::RefCell;
+use core::future::poll_fn;
+use core::task::{Poll, Waker};
+
+use embedded_io::ErrorKind;
+use smoltcp::iface::{SocketHandle, SocketSet};
+use smoltcp::socket::tcp;
+
+/// Adapter from a `smoltcp` TCP socket.
+///
+/// A `smoltcp` socket only makes progress when the [`Interface`](smoltcp::iface::Interface)
+/// is polled with the [`SocketSet`] containing it. For this reason, the adapter doesn't own the socket:
+/// it borrows the `SocketSet` from a `RefCell` for the duration of each operation, and refers to the
+/// socket by its [`SocketHandle`].
+///
+/// - The `embedded-io-async` traits register the socket wakers, and rely on another task polling
+///   the interface. The `poll` closure is never called.
+/// - The `embedded-io` traits call the `poll` closure in a loop until the operation can complete.
+///   The closure must poll the interface, borrowing the same `SocketSet`.
+///
+/// Errors are mapped as follows:
+///
+/// - Reading from a socket whose remote end has closed the connection returns `Ok(0)` (EOF).
+/// - Reading from or writing to a socket in a state that doesn't allow it returns [`ErrorKind::NotConnected`].
+/// - Flushing a socket that has been closed before all data was acknowledged returns [`ErrorKind::ConnectionReset`].
+pub struct SmoltcpTcpStream<'a, 's, P> {
+    sockets: &'a RefCell<SocketSet<'s>>,
+    handle: SocketHandle,
+    poll: P,
+}
+
+impl<'a, 's, P> SmoltcpTcpStream<'a, 's, P> {
+    /// Create a new adapter for the TCP socket `handle` in `sockets`.
+    ///
+    /// `poll` is only used by the blocking `embedded-io` traits. When only using the
+    /// `embedded-io-async` traits, pass an empty closure.
+    pub fn new(sockets: &'a RefCell<SocketSet<'s>>, handle: SocketHandle, poll: P) -> Self {
+        Self {
+            sockets,
+            handle,
+            poll,
+        }
+    }
+
+    /// Returns the handle of the socket.
+    pub fn handle(&self) -> SocketHandle {
+        self.handle
+    }
+
+    fn poll_read(
+        &mut self,
+        buf: &mut [u8],
+        waker: Option<&Waker>,
+    ) -> Poll<Result<usize, ErrorKind>> {
+        let mut sockets = self.sockets.borrow_mut();
+        let socket = sockets.get_mut::<tcp::Socket>(self.handle);
+        match socket.recv_slice(buf) {
+            Ok(0) if !buf.is_empty() => {
+                if let Some(waker) = waker {
+                    socket.register_recv_waker(waker);
+                }
+                Poll::Pending
+            }
+            Ok(n) => Poll::Ready(Ok(n)),
+            Err(tcp::RecvError::Finished) => Poll::Ready(Ok(0)),
+            Err(tcp::RecvError::InvalidState) => Poll::Ready(Err(ErrorKind::NotConnected)),
+        }
+    }
+
+    fn poll_write(&mut self, buf: &[u8], waker: Option<&Waker>) -> Poll<Result<usize, ErrorKind>> {
+        let mut sockets = self.sockets.borrow_mut();
+        let socket = sockets.get_mut::<tcp::Socket>(self.handle);
+        match socket.send_slice(buf) {
+            Ok(0) if !buf.is_empty() => {
+                if let Some(waker) = waker {
+                    socket.register_send_waker(waker);
+                }
+                Poll::Pending
+            }
+            Ok(n) => Poll::Ready(Ok(n)),
+            Err(tcp::SendError::InvalidState) => Poll::Ready(Err(ErrorKind::NotConnected)),
+        }
+    }
+
+    fn poll_flush(&mut self, waker: Option<&Waker>) -> Poll<Result<(), ErrorKind>> {
+        let mut sockets = self.sockets.borrow_mut();
+        let socket = sockets.get_mut::<tcp::Socket>(self.handle);
+        if socket.send_queue() == 0 {
+            Poll::Ready(Ok(()))
+        } else if socket.state() == tcp::State::Closed {
+            Poll::Ready(Err(ErrorKind::ConnectionReset))
+        } else {
+            if let Some(waker) = waker {
+                socket.register_send_waker(waker);
+            }
+            Poll::Pending
+        }
+    }
+}
+
+impl<P> embedded_io::ErrorType for SmoltcpTcpStream<'_, '_, P> {
+    type Error = ErrorKind;
+}
+
+impl<P: FnMut()> embedded_io::Read for SmoltcpTcpStream<'_, '_, P> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        loop {
+            if let Poll::Ready(res) = self.poll_read(buf, None) {
+                return res;
+            }
+            (self.poll)();
+        }
+    }
+}
+
+impl<P: FnMut()> embedded_io::Write for SmoltcpTcpStream<'_, '_, P> {
+    fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+        loop {
+            if let Poll::Ready(res) = self.poll_write(buf, None) {
+                return res;
+            }
+            (self.poll)();
+        }
+    }
+
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        loop {
+            if let Poll::Ready(res) = self.poll_flush(None) {
+                return res;
+            }
+            (self.poll)();
+        }
+    }
+}
+
+impl<P> embedded_io::ReadReady for SmoltcpTcpStream<'_, '_, P> {
+    fn read_ready(&mut self) -> Result<bool, Self::Error> {
+        let sockets = self.sockets.borrow();
+        let socket = sockets.get::<tcp::Socket>(self.handle);
+        Ok(socket.can_recv() || !socket.may_recv())
+    }
+}
+
+impl<P> embedded_io::WriteReady for SmoltcpTcpStream<'_, '_, P> {
+    fn write_ready(&mut self) -> Result<bool, Self::Error> {
+        let sockets = self.sockets.borrow();
+        let socket = sockets.get::<tcp::Socket>(self.handle);
+        Ok(socket.can_send() || !socket.may_send())
+    }
+}
+
+impl<P> embedded_io_async::Read for SmoltcpTcpStream<'_, '_, P> {
+    async fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        poll_fn(|cx| self.poll_read(buf, Some(cx.waker()))).await
+    }
+}
+
+impl<P> embedded_io_async::Write for SmoltcpTcpStream<'_, '_, P> {
+    async fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+        poll_fn(|cx| self.poll_write(buf, Some(cx.waker()))).await
+    }
+
+    async fn flush(&mut self) -> Result<(), Self::Error> {
+        poll_fn(|cx| self.poll_flush(Some(cx.waker()))).await
+    }
+}
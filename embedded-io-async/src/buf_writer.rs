@@ -0,0 +1,204 @@
+use crate::{ErrorType, Write};
+
+/// Async writer adapter which buffers writes to the underlying writer.
+///
+/// Writing to some writers is expensive when done in small chunks, for example a UART using DMA,
+/// where each write has a fixed setup cost. `BufWriter` keeps data in an internal `N`-byte buffer,
+/// and only writes it to the underlying writer when the buffer is full, or when flushed.
+///
+/// This is the `embedded-io-async` equivalent of [`std::io::BufWriter`].
+///
+/// Unlike its `std` counterpart, `BufWriter` does not flush its buffer when dropped, since that
+/// would require waiting. Call [`flush`](Write::flush) before dropping it or calling
+/// [`into_inner`](BufWriter::into_inner), or buffered data will be lost.
+pub struct BufWriter<const N: usize, W> {
+    inner: W,
+    buf: [u8; N],
+    /// Start of the data not yet written to the underlying writer.
+    pos: usize,
+    /// End of the buffered data.
+    len: usize,
+}
+
+impl<const N: usize, W> BufWriter<N, W> {
+    /// Creates a new `BufWriter` with an empty buffer.
+    #[inline]
+    pub fn new(inner: W) -> Self {
+        Self {
+            inner,
+            buf: [0; N],
+            pos: 0,
+            len: 0,
+        }
+    }
+
+    /// Returns the data currently buffered, not yet written to the underlying writer.
+    #[inline]
+    pub fn buffer(&self) -> &[u8] {
+        &self.buf[self.pos..self.len]
+    }
+
+    /// Gets a reference to the underlying writer.
+    #[inline]
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the underlying writer.
+    ///
+    /// It is inadvisable to directly write to the underlying writer, since buffered data
+    /// would be written after it.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Consumes the `BufWriter`, returning the underlying writer.
+    ///
+    /// Any data still in the buffer is lost, call [`flush_buf`](BufWriter::flush_buf) first.
+    #[inline]
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<const N: usize, W: Write> BufWriter<N, W> {
+    /// Writes all buffered data to the underlying writer, without flushing it.
+    ///
+    /// On error, the data that could not be written is kept in the buffer.
+    ///
+    /// This function is cancel-safe: if the returned future is dropped before completion, the
+    /// data already written is removed from the buffer, and the rest is kept.
+    pub async fn flush_buf(&mut self) -> Result<(), W::Error> {
+        while self.pos < self.len {
+            match self.inner.write(&self.buf[self.pos..self.len]).await? {
+                0 => panic!("write() returned Ok(0)"),
+                n => self.pos += n,
+            }
+        }
+        self.pos = 0;
+        self.len = 0;
+        Ok(())
+    }
+}
+
+impl<const N: usize, W: ErrorType> ErrorType for BufWriter<N, W> {
+    type Error = W::Error;
+}
+
+impl<const N: usize, W: Write> Write for BufWriter<N, W> {
+    async fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+        if self.len == N {
+            self.flush_buf().await?;
+        }
+        // Bypass the buffer for writes at least as large as it.
+        if self.len == 0 && buf.len() >= N {
+            return self.inner.write(buf).await;
+        }
+        let n = core::cmp::min(buf.len(), N - self.len);
+        self.buf[self.len..self.len + n].copy_from_slice(&buf[..n]);
+        self.len += n;
+        Ok(n)
+    }
+
+    async fn flush(&mut self) -> Result<(), Self::Error> {
+        self.flush_buf().await?;
+        self.inner.flush().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::block_on;
+
+    /// Writer accepting at most `max_write` bytes per call.
+    struct ShortWriter {
+        buf: [u8; 32],
+        len: usize,
+        max_write: usize,
+        writes: usize,
+    }
+
+    impl ShortWriter {
+        fn new(max_write: usize) -> Self {
+            Self {
+                buf: [0; 32],
+                len: 0,
+                max_write,
+                writes: 0,
+            }
+        }
+
+        fn written(&self) -> &[u8] {
+            &self.buf[..self.len]
+        }
+    }
+
+    impl ErrorType for ShortWriter {
+        type Error = core::convert::Infallible;
+    }
+
+    impl Write for ShortWriter {
+        async fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+            let n = buf.len().min(self.max_write);
+            self.buf[self.len..self.len + n].copy_from_slice(&buf[..n]);
+            self.len += n;
+            self.writes += 1;
+            Ok(n)
+        }
+    }
+
+    #[test]
+    fn buffered() {
+        let mut writer: BufWriter<4, _> = BufWriter::new(ShortWriter::new(8));
+        assert_eq!(block_on(writer.write(b"ab")), Ok(2));
+        assert_eq!(block_on(writer.write(b"cde")), Ok(2));
+        assert_eq!(writer.buffer(), b"abcd");
+        assert_eq!(writer.get_ref().writes, 0);
+
+        // The full buffer is written before buffering more data.
+        assert_eq!(block_on(writer.write(b"e")), Ok(1));
+        assert_eq!(writer.get_ref().written(), b"abcd");
+        assert_eq!(writer.buffer(), b"e");
+    }
+
+    #[test]
+    fn flush_buf_resumes_short_writes() {
+        let mut writer: BufWriter<8, _> = BufWriter::new(ShortWriter::new(3));
+        block_on(writer.write_all(b"abcdefg")).unwrap();
+        block_on(writer.flush_buf()).unwrap();
+        assert_eq!(writer.get_ref().written(), b"abcdefg");
+        assert_eq!(writer.get_ref().writes, 3);
+        assert!(writer.buffer().is_empty());
+
+        block_on(writer.write_all(b"hi")).unwrap();
+        block_on(writer.flush()).unwrap();
+        assert_eq!(writer.get_ref().written(), b"abcdefghi");
+    }
+
+    #[test]
+    fn large_write_bypasses_buffer() {
+        let mut writer: BufWriter<4, _> = BufWriter::new(ShortWriter::new(16));
+        assert_eq!(block_on(writer.write(b"abcdef")), Ok(6));
+        assert_eq!(writer.get_ref().writes, 1);
+        assert!(writer.buffer().is_empty());
+
+        // Not bypassed while data is buffered, to keep the order of the bytes.
+        assert_eq!(block_on(writer.write(b"g")), Ok(1));
+        assert_eq!(block_on(writer.write(b"hijklm")), Ok(3));
+        assert_eq!(writer.buffer(), b"ghij");
+        assert_eq!(writer.get_ref().written(), b"abcdef");
+    }
+
+    #[test]
+    #[should_panic(expected = "write() returned Ok(0)")]
+    fn flush_buf_panics_on_zero_write() {
+        let mut writer: BufWriter<4, _> = BufWriter::new(ShortWriter::new(0));
+        block_on(writer.write(b"ab")).unwrap();
+        let _ = block_on(writer.flush_buf());
+    }
+}
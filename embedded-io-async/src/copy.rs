@@ -0,0 +1,51 @@
+use crate::{Read, Write};
+
+/// Size of the stack buffer used by [`copy`] and [`copy_n`].
+const BUF_SIZE: usize = 64;
+
+/// Copies the entire contents of a reader into a writer.
+///
+/// This function reads from `reader` until EOF, writing everything to `writer`, and returns
+/// the number of bytes copied. Data is copied through a 64-byte buffer.
+///
+/// This is the `embedded-io-async` equivalent of [`std::io::copy`].
+///
+/// `writer.flush()` is not called after the copy completes.
+///
+/// This function is not side-effect-free on cancel (AKA "cancel-safe"), i.e. if you cancel (drop) a returned
+/// future that hasn't completed yet, some bytes might have been read without being written, which will get lost.
+pub async fn copy<R, W>(reader: &mut R, writer: &mut W) -> Result<u64, R::Error>
+where
+    R: Read + ?Sized,
+    W: Write<Error = R::Error> + ?Sized,
+{
+    copy_n(reader, writer, u64::MAX).await
+}
+
+/// Copies at most `n` bytes from a reader into a writer.
+///
+/// This function stops after copying `n` bytes, or when `reader` reaches EOF, whichever comes
+/// first, and returns the number of bytes copied.
+///
+/// `writer.flush()` is not called after the copy completes.
+///
+/// This function is not side-effect-free on cancel (AKA "cancel-safe"), i.e. if you cancel (drop) a returned
+/// future that hasn't completed yet, some bytes might have been read without being written, which will get lost.
+pub async fn copy_n<R, W>(reader: &mut R, writer: &mut W, n: u64) -> Result<u64, R::Error>
+where
+    R: Read + ?Sized,
+    W: Write<Error = R::Error> + ?Sized,
+{
+    let mut buf = [0; BUF_SIZE];
+    let mut copied = 0;
+    while copied < n {
+        let len = core::cmp::min(BUF_SIZE as u64, n - copied) as usize;
+        let read = reader.read(&mut buf[..len]).await?;
+        if read == 0 {
+            break;
+        }
+        writer.write_all(&buf[..read]).await?;
+        copied += read as u64;
+    }
+    Ok(copied)
+}
@@ -0,0 +1,141 @@
+use crate::{BufRead, ErrorKind, ErrorType, Read, Seek, SeekFrom, Write};
+
+/// Async equivalent of [`embedded_io::Cursor`].
+///
+/// `AsyncCursor` wraps an in-memory buffer, and implements the async [`Read`], [`BufRead`] and
+/// [`Seek`] traits for it, as well as [`Write`] for `AsyncCursor<&mut [u8]>` (and
+/// `AsyncCursor<Vec<u8>>` with the `alloc` feature). All operations complete immediately.
+///
+/// This is mostly useful to test code written against the async traits.
+///
+/// ```rust
+/// use embedded_io_async::{AsyncCursor, Read};
+///
+/// async fn read_header() {
+///     let mut cursor = AsyncCursor::new([1u8, 2, 3, 4, 5]);
+///     let mut buf = [0u8; 2];
+///     cursor.read_exact(&mut buf).await.unwrap();
+///
+///     assert_eq!(buf, [1, 2]);
+///     assert_eq!(cursor.position(), 2);
+/// }
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub struct AsyncCursor<T> {
+    inner: embedded_io::Cursor<T>,
+}
+
+impl<T> AsyncCursor<T> {
+    /// Creates a new cursor wrapping the provided underlying in-memory buffer.
+    ///
+    /// The initial position of the cursor is `0`.
+    #[inline]
+    pub const fn new(inner: T) -> Self {
+        Self {
+            inner: embedded_io::Cursor::new(inner),
+        }
+    }
+
+    /// Consumes this cursor, returning the underlying value.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.inner.into_inner()
+    }
+
+    /// Gets a reference to the underlying value in this cursor.
+    #[inline]
+    pub const fn get_ref(&self) -> &T {
+        self.inner.get_ref()
+    }
+
+    /// Gets a mutable reference to the underlying value in this cursor.
+    ///
+    /// Care should be taken to avoid modifying the internal I/O state of the
+    /// underlying value as it may corrupt this cursor's position.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut T {
+        self.inner.get_mut()
+    }
+
+    /// Returns the current position of this cursor.
+    #[inline]
+    pub const fn position(&self) -> u64 {
+        self.inner.position()
+    }
+
+    /// Sets the position of this cursor.
+    #[inline]
+    pub fn set_position(&mut self, pos: u64) {
+        self.inner.set_position(pos);
+    }
+}
+
+impl<T> From<embedded_io::Cursor<T>> for AsyncCursor<T> {
+    #[inline]
+    fn from(inner: embedded_io::Cursor<T>) -> Self {
+        Self { inner }
+    }
+}
+
+impl<T> ErrorType for AsyncCursor<T> {
+    type Error = ErrorKind;
+}
+
+impl<T: AsRef<[u8]>> Read for AsyncCursor<T> {
+    #[inline]
+    async fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        embedded_io::Read::read(&mut self.inner, buf)
+    }
+}
+
+impl<T: AsRef<[u8]>> BufRead for AsyncCursor<T> {
+    #[inline]
+    async fn fill_buf(&mut self) -> Result<&[u8], Self::Error> {
+        embedded_io::BufRead::fill_buf(&mut self.inner)
+    }
+
+    #[inline]
+    fn consume(&mut self, amt: usize) {
+        embedded_io::BufRead::consume(&mut self.inner, amt);
+    }
+}
+
+impl<T: AsRef<[u8]>> Seek for AsyncCursor<T> {
+    #[inline]
+    async fn seek(&mut self, pos: SeekFrom) -> Result<u64, Self::Error> {
+        embedded_io::Seek::seek(&mut self.inner, pos)
+    }
+}
+
+/// Write is implemented for `AsyncCursor<&mut [u8]>` by copying into the slice at the current
+/// position, overwriting its data.
+///
+/// If the cursor is at the end of the slice, write operations return an error of kind
+/// [`ErrorKind::WriteZero`].
+impl Write for AsyncCursor<&mut [u8]> {
+    #[inline]
+    async fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+        embedded_io::Write::write(&mut self.inner, buf)
+    }
+
+    #[inline]
+    async fn flush(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+/// Write is implemented for `AsyncCursor<Vec<u8>>` by overwriting the vector at the current
+/// position, growing it as needed.
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "std", feature = "alloc"))))]
+impl Write for AsyncCursor<alloc::vec::Vec<u8>> {
+    #[inline]
+    async fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+        embedded_io::Write::write(&mut self.inner, buf)
+    }
+
+    #[inline]
+    async fn flush(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
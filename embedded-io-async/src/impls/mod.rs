@@ -1,3 +1,5 @@
+#[cfg(target_has_atomic = "ptr")]
+mod pipe;
 mod slice_mut;
 mod slice_ref;
 
@@ -0,0 +1,19 @@
+use core::future::poll_fn;
+
+use embedded_io::{PipeReader, PipeWriter};
+
+use crate::{Read, Write};
+
+/// Waits until the pipe is not empty.
+impl<const N: usize> Read for PipeReader<'_, N> {
+    async fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        Ok(poll_fn(|cx| self.poll_read(cx, buf)).await)
+    }
+}
+
+/// Waits until the pipe is not full.
+impl<const N: usize> Write for PipeWriter<'_, N> {
+    async fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+        Ok(poll_fn(|cx| self.poll_write(cx, buf)).await)
+    }
+}
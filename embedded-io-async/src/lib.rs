@@ -7,10 +7,23 @@
 #[cfg(feature = "alloc")]
 extern crate alloc;
 
+mod buf_writer;
+mod copy;
+mod cursor;
 mod impls;
+mod pipeline;
+mod seek_adapter;
+mod take;
+
+pub use buf_writer::BufWriter;
+pub use copy::{copy, copy_n};
+pub use cursor::AsyncCursor;
+pub use pipeline::Pipeline;
+pub use seek_adapter::AsyncSeekAdapter;
+pub use take::Take;
 
 pub use embedded_io::{
-    Error, ErrorKind, ErrorType, ReadExactError, ReadReady, SeekFrom, WriteReady,
+    Error, ErrorKind, ErrorType, ReadExactError, ReadReady, SeekFrom, WriteFmtError, WriteReady,
 };
 
 /// Async reader.
@@ -77,6 +90,53 @@ pub trait Read: ErrorType {
             Err(ReadExactError::UnexpectedEof)
         }
     }
+
+    /// Read all bytes until EOF, appending them to `buf`.
+    ///
+    /// This function calls `read()` in a loop until it returns `Ok(0)`, waiting if needed.
+    /// The number of bytes appended to `buf` is returned.
+    ///
+    /// `buf` is grown in fixed chunks of 64 bytes, and truncated to the amount of data actually
+    /// read after each call to `read()`.
+    ///
+    /// If an error is returned, all bytes read so far are kept in `buf`.
+    ///
+    /// This function is not side-effect-free on cancel (AKA "cancel-safe"), i.e. if you cancel (drop) a returned
+    /// future that hasn't completed yet, some bytes might have already been read, and `buf` may contain
+    /// trailing zero bytes that were not read from the stream.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "std", feature = "alloc"))))]
+    async fn read_to_end(&mut self, buf: &mut alloc::vec::Vec<u8>) -> Result<usize, Self::Error> {
+        const CHUNK_SIZE: usize = 64;
+
+        let start_len = buf.len();
+        loop {
+            let len = buf.len();
+            buf.resize(len + CHUNK_SIZE, 0);
+            match self.read(&mut buf[len..]).await {
+                Ok(0) => {
+                    buf.truncate(len);
+                    return Ok(len - start_len);
+                }
+                Ok(n) => buf.truncate(len + n),
+                Err(e) => {
+                    buf.truncate(len);
+                    return Err(e);
+                }
+            }
+        }
+    }
+
+    /// Creates an adapter which will read at most `limit` bytes from this reader.
+    ///
+    /// This function returns a new instance of `Read` which will read at most
+    /// `limit` bytes, after which it will always return EOF (`Ok(0)`).
+    fn take(self, limit: u64) -> Take<Self>
+    where
+        Self: Sized,
+    {
+        Take::new(self, limit)
+    }
 }
 
 /// Async buffered reader.
@@ -94,6 +154,48 @@ pub trait BufRead: ErrorType {
 
     /// Tell this buffer that `amt` bytes have been consumed from the buffer, so they should no longer be returned in calls to `fill_buf`.
     fn consume(&mut self, amt: usize);
+
+    /// Read bytes into `buf` until the delimiter `byte` or EOF is reached, or `buf` is full.
+    ///
+    /// If the delimiter is found, it is included in `buf`. Returns the number of bytes read.
+    /// If the returned amount is `buf.len()` and the last byte isn't the delimiter, `buf` was too
+    /// small to hold the entire data up to the delimiter, and the rest can be read with another call.
+    ///
+    /// This function waits until the delimiter is found, EOF is reached, or `buf` is full.
+    ///
+    /// This function is not side-effect-free on cancel (AKA "cancel-safe"), i.e. if you cancel (drop) a returned
+    /// future that hasn't completed yet, some bytes might have already been read, which will get lost.
+    async fn read_until(&mut self, byte: u8, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        let mut read = 0;
+        while read < buf.len() {
+            let available = self.fill_buf().await?;
+            if available.is_empty() {
+                break;
+            }
+            let len = core::cmp::min(available.len(), buf.len() - read);
+            let (found, used) = match available[..len].iter().position(|&b| b == byte) {
+                Some(i) => (true, i + 1),
+                None => (false, len),
+            };
+            buf[read..read + used].copy_from_slice(&available[..used]);
+            self.consume(used);
+            read += used;
+            if found {
+                break;
+            }
+        }
+        Ok(read)
+    }
+
+    /// Read a line into `buf`.
+    ///
+    /// This is equivalent to `read_until(b'\n', buf)`: the newline is included in `buf` if found.
+    ///
+    /// This function is not side-effect-free on cancel (AKA "cancel-safe"), i.e. if you cancel (drop) a returned
+    /// future that hasn't completed yet, some bytes might have already been read, which will get lost.
+    async fn read_line(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        self.read_until(b'\n', buf).await
+    }
 }
 
 /// Async writer.
@@ -138,6 +240,9 @@ pub trait Write: ErrorType {
     ///
     /// This function is not side-effect-free on cancel (AKA "cancel-safe"), i.e. if you cancel (drop) a returned
     /// future that hasn't completed yet, some bytes might have already been written.
+    ///
+    /// Implementations can override this method, for example to write the whole buffer with a single
+    /// DMA transfer instead of several calls to `write()`.
     async fn write_all(&mut self, buf: &[u8]) -> Result<(), Self::Error> {
         let mut buf = buf;
         while !buf.is_empty() {
@@ -149,6 +254,77 @@ pub trait Write: ErrorType {
         }
         Ok(())
     }
+
+    /// Write a formatted string into this writer, returning any error encountered.
+    ///
+    /// This function is called by the `write!` macro, so it can be used like
+    /// `write!(socket, "HTTP/1.1 200 OK\r\n").await`.
+    ///
+    /// `core::fmt` can't wait, so the output is formatted into a 64-byte buffer on the stack which
+    /// is written out with `write_all()` whenever it's full. To avoid allocating, the arguments are
+    /// formatted again for each chunk, skipping the bytes already written:
+    ///
+    /// - The `Display`/`Debug` implementations of the arguments **must** produce the same output
+    ///   each time they are called. Otherwise, the output written is corrupted, without any error.
+    /// - Formatting takes time quadratic in the length of the output. For long outputs, format
+    ///   into a buffer first, and write it with `write_all()`.
+    ///
+    /// This function is not side-effect-free on cancel (AKA "cancel-safe"), i.e. if you cancel (drop) a returned
+    /// future that hasn't completed yet, some bytes might have already been written.
+    async fn write_fmt(
+        &mut self,
+        fmt: core::fmt::Arguments<'_>,
+    ) -> Result<(), WriteFmtError<Self::Error>> {
+        const CHUNK_SIZE: usize = 64;
+
+        // Formats into a fixed-size buffer, skipping the first `skip` bytes of output,
+        // and stops with an error once the buffer is full.
+        struct Adapter {
+            buf: [u8; CHUNK_SIZE],
+            len: usize,
+            skip: usize,
+            full: bool,
+        }
+
+        impl core::fmt::Write for Adapter {
+            fn write_str(&mut self, s: &str) -> core::fmt::Result {
+                let skip = core::cmp::min(self.skip, s.len());
+                self.skip -= skip;
+                let bytes = &s.as_bytes()[skip..];
+                let n = core::cmp::min(bytes.len(), CHUNK_SIZE - self.len);
+                self.buf[self.len..self.len + n].copy_from_slice(&bytes[..n]);
+                self.len += n;
+                if n < bytes.len() {
+                    self.full = true;
+                    return Err(core::fmt::Error);
+                }
+                Ok(())
+            }
+        }
+
+        if let Some(s) = fmt.as_str() {
+            return Ok(self.write_all(s.as_bytes()).await?);
+        }
+
+        let mut written = 0;
+        loop {
+            let mut output = Adapter {
+                buf: [0; CHUNK_SIZE],
+                len: 0,
+                skip: written,
+                full: false,
+            };
+            // check if the error came from the buffer being full or not
+            if core::fmt::write(&mut output, fmt).is_err() && !output.full {
+                return Err(WriteFmtError::FmtError);
+            }
+            self.write_all(&output.buf[..output.len]).await?;
+            written += output.len;
+            if !output.full {
+                return Ok(());
+            }
+        }
+    }
 }
 
 /// Async seek within streams.
@@ -197,6 +373,11 @@ impl<T: ?Sized + Write> Write for &mut T {
     async fn flush(&mut self) -> Result<(), Self::Error> {
         T::flush(self).await
     }
+
+    #[inline]
+    async fn write_all(&mut self, buf: &[u8]) -> Result<(), Self::Error> {
+        T::write_all(self, buf).await
+    }
 }
 
 impl<T: ?Sized + Seek> Seek for &mut T {
@@ -204,4 +385,96 @@ impl<T: ?Sized + Seek> Seek for &mut T {
     async fn seek(&mut self, pos: SeekFrom) -> Result<u64, Self::Error> {
         T::seek(self, pos).await
     }
+
+    #[inline]
+    async fn rewind(&mut self) -> Result<(), Self::Error> {
+        T::rewind(self).await
+    }
+
+    #[inline]
+    async fn stream_position(&mut self) -> Result<u64, Self::Error> {
+        T::stream_position(self).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::future::Future;
+    use core::pin::pin;
+    use core::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+
+    use super::*;
+
+    /// Runs a future which completes without waiting.
+    pub(crate) fn block_on<F: Future>(fut: F) -> F::Output {
+        const VTABLE: RawWakerVTable = RawWakerVTable::new(
+            |_| RawWaker::new(core::ptr::null(), &VTABLE),
+            |_| {},
+            |_| {},
+            |_| {},
+        );
+        // SAFETY: the vtable functions do nothing, so they are valid for any data pointer.
+        let waker = unsafe { Waker::from_raw(RawWaker::new(core::ptr::null(), &VTABLE)) };
+        match pin!(fut).poll(&mut Context::from_waker(&waker)) {
+            Poll::Ready(output) => output,
+            Poll::Pending => panic!("future is waiting"),
+        }
+    }
+
+    /// Formats as `len` bytes, `0123456789` repeated, forcing `write_fmt` to use chunks.
+    struct Digits(usize);
+
+    impl core::fmt::Display for Digits {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            for i in 0..self.0 {
+                write!(f, "{}", i % 10)?;
+            }
+            Ok(())
+        }
+    }
+
+    /// Fails after formatting a few bytes.
+    struct Failing;
+
+    impl core::fmt::Display for Failing {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            f.write_str("abc")?;
+            Err(core::fmt::Error)
+        }
+    }
+
+    #[test]
+    fn write_fmt_chunks() {
+        for len in [0, 1, 63, 64, 65, 128, 200] {
+            let mut buf = [0; 256];
+            let mut writer = &mut buf[..];
+            block_on(writer.write_fmt(format_args!("{}", Digits(len)))).unwrap();
+            let remaining = writer.len();
+            assert_eq!(remaining, buf.len() - len, "len {len}");
+            for (i, b) in buf[..len].iter().enumerate() {
+                assert_eq!(*b, b'0' + (i % 10) as u8, "len {len}");
+            }
+        }
+    }
+
+    #[test]
+    fn write_fmt_fmt_error() {
+        let mut buf = [0; 16];
+        let mut writer = &mut buf[..];
+        assert_eq!(
+            block_on(writer.write_fmt(format_args!("{}", Failing))),
+            Err(WriteFmtError::FmtError)
+        );
+    }
+
+    #[test]
+    fn write_fmt_writer_error() {
+        let mut buf = [0; 16];
+        let mut writer = &mut buf[..];
+        assert_eq!(
+            block_on(writer.write_fmt(format_args!("{}", Digits(65)))),
+            Err(WriteFmtError::Other(embedded_io::SliceWriteError::Full))
+        );
+        assert_eq!(buf, *b"0123456789012345");
+    }
 }
@@ -0,0 +1,146 @@
+use core::future::{poll_fn, Future};
+use core::pin::pin;
+use core::task::Poll;
+
+use crate::{ErrorType, Read, Write};
+
+/// Size of each of the two stack buffers used by [`Pipeline::copy_with`].
+const BUF_SIZE: usize = 64;
+
+/// A reader and a writer combined into a single stream.
+///
+/// This is the asynchronous version of [`embedded_io::Pipeline`].
+///
+/// Unlike the blocking version, [`copy_with`](Pipeline::copy_with) overlaps reads and writes:
+/// the next chunk is read while the previous one is written, hiding the latency of the slowest
+/// side.
+#[derive(Debug, Clone)]
+pub struct Pipeline<R, W> {
+    reader: R,
+    writer: W,
+}
+
+impl<R, W> Pipeline<R, W> {
+    /// Create a new `Pipeline` from a reader and a writer.
+    #[inline]
+    pub fn new(reader: R, writer: W) -> Self {
+        Self { reader, writer }
+    }
+
+    /// Consumes the `Pipeline`, returning the reader and the writer.
+    #[inline]
+    pub fn split(self) -> (R, W) {
+        (self.reader, self.writer)
+    }
+
+    /// Gets a reference to the reader.
+    #[inline]
+    pub fn reader(&self) -> &R {
+        &self.reader
+    }
+
+    /// Gets a mutable reference to the reader.
+    #[inline]
+    pub fn reader_mut(&mut self) -> &mut R {
+        &mut self.reader
+    }
+
+    /// Gets a reference to the writer.
+    #[inline]
+    pub fn writer(&self) -> &W {
+        &self.writer
+    }
+
+    /// Gets a mutable reference to the writer.
+    #[inline]
+    pub fn writer_mut(&mut self) -> &mut W {
+        &mut self.writer
+    }
+}
+
+impl<R: Read, W: Write<Error = R::Error>> Pipeline<R, W> {
+    /// Copies the entire contents of the reader into the writer, calling `f` on each chunk
+    /// before writing it.
+    ///
+    /// This reads until EOF, and returns the number of bytes copied. Data is copied through two
+    /// 64-byte buffers on the stack: while a chunk is written, the next one is read into the
+    /// other buffer. `f` may modify each chunk in place.
+    ///
+    /// `flush` is not called on the writer after the copy completes.
+    ///
+    /// This function is not side-effect-free on cancel (AKA "cancel-safe"), i.e. if you cancel (drop) a returned
+    /// future that hasn't completed yet, some bytes might have been read without being written, which will get lost.
+    pub async fn copy_with(&mut self, mut f: impl FnMut(&mut [u8])) -> Result<u64, R::Error> {
+        let mut bufs = [[0; BUF_SIZE]; 2];
+        let (front, back) = bufs.split_at_mut(1);
+        let (mut front, mut back) = (&mut front[0], &mut back[0]);
+
+        let mut read = self.reader.read(front).await?;
+        let mut copied = 0;
+        while read != 0 {
+            f(&mut front[..read]);
+            let (write_res, read_res) = join(
+                self.writer.write_all(&front[..read]),
+                self.reader.read(back),
+            )
+            .await;
+            write_res?;
+            copied += read as u64;
+            read = read_res?;
+            core::mem::swap(&mut front, &mut back);
+        }
+        Ok(copied)
+    }
+}
+
+/// Runs two futures concurrently, returning both outputs.
+async fn join<A: Future, B: Future>(a: A, b: B) -> (A::Output, B::Output) {
+    let mut a = pin!(a);
+    let mut b = pin!(b);
+    let mut a_out = None;
+    let mut b_out = None;
+    poll_fn(|cx| {
+        if a_out.is_none() {
+            if let Poll::Ready(out) = a.as_mut().poll(cx) {
+                a_out = Some(out);
+            }
+        }
+        if b_out.is_none() {
+            if let Poll::Ready(out) = b.as_mut().poll(cx) {
+                b_out = Some(out);
+            }
+        }
+        match (a_out.take(), b_out.take()) {
+            (Some(a), Some(b)) => Poll::Ready((a, b)),
+            (a, b) => {
+                a_out = a;
+                b_out = b;
+                Poll::Pending
+            }
+        }
+    })
+    .await
+}
+
+impl<R: ErrorType, W: ErrorType<Error = R::Error>> ErrorType for Pipeline<R, W> {
+    type Error = R::Error;
+}
+
+impl<R: Read, W: ErrorType<Error = R::Error>> Read for Pipeline<R, W> {
+    #[inline]
+    async fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        self.reader.read(buf).await
+    }
+}
+
+impl<R: ErrorType, W: Write<Error = R::Error>> Write for Pipeline<R, W> {
+    #[inline]
+    async fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+        self.writer.write(buf).await
+    }
+
+    #[inline]
+    async fn flush(&mut self) -> Result<(), Self::Error> {
+        self.writer.flush().await
+    }
+}
@@ -0,0 +1,92 @@
+use crate::{BufRead, ErrorType, Read, Seek, SeekFrom, Write};
+
+/// Adapter implementing the async traits for a blocking stream.
+///
+/// Each async operation calls the corresponding blocking [`embedded_io`] operation, and completes
+/// immediately. This allows passing blocking seekable streams, such as files on an in-memory
+/// filesystem, to code written against the async [`Seek`] trait.
+///
+/// The blocking operations are run to completion when polled, so this adapter should only be used
+/// with streams that don't block for long, or in contexts where blocking the executor is acceptable.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AsyncSeekAdapter<T> {
+    inner: T,
+}
+
+impl<T> AsyncSeekAdapter<T> {
+    /// Creates a new adapter.
+    #[inline]
+    pub fn new(inner: T) -> Self {
+        Self { inner }
+    }
+
+    /// Gets a reference to the underlying stream.
+    #[inline]
+    pub fn get_ref(&self) -> &T {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the underlying stream.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut T {
+        &mut self.inner
+    }
+
+    /// Consumes the adapter, returning the underlying stream.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+impl<T: ErrorType> ErrorType for AsyncSeekAdapter<T> {
+    type Error = T::Error;
+}
+
+impl<T: embedded_io::Seek> Seek for AsyncSeekAdapter<T> {
+    #[inline]
+    async fn seek(&mut self, pos: SeekFrom) -> Result<u64, Self::Error> {
+        self.inner.seek(pos)
+    }
+
+    #[inline]
+    async fn rewind(&mut self) -> Result<(), Self::Error> {
+        self.inner.rewind()
+    }
+
+    #[inline]
+    async fn stream_position(&mut self) -> Result<u64, Self::Error> {
+        self.inner.stream_position()
+    }
+}
+
+impl<T: embedded_io::Read> Read for AsyncSeekAdapter<T> {
+    #[inline]
+    async fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        self.inner.read(buf)
+    }
+}
+
+impl<T: embedded_io::BufRead> BufRead for AsyncSeekAdapter<T> {
+    #[inline]
+    async fn fill_buf(&mut self) -> Result<&[u8], Self::Error> {
+        self.inner.fill_buf()
+    }
+
+    #[inline]
+    fn consume(&mut self, amt: usize) {
+        self.inner.consume(amt);
+    }
+}
+
+impl<T: embedded_io::Write> Write for AsyncSeekAdapter<T> {
+    #[inline]
+    async fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+        self.inner.write(buf)
+    }
+
+    #[inline]
+    async fn flush(&mut self) -> Result<(), Self::Error> {
+        self.inner.flush()
+    }
+}
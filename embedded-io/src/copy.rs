@@ -0,0 +1,55 @@
+use crate::{Read, Write};
+
+/// Size of the stack buffer used by [`copy`] and [`copy_n`].
+const BUF_SIZE: usize = 64;
+
+/// Copies the entire contents of a reader into a writer.
+///
+/// This function reads from `reader` until EOF, writing everything to `writer`, and returns
+/// the number of bytes copied. Data is copied through a 64-byte buffer on the stack.
+///
+/// This is the `embedded-io` equivalent of [`std::io::copy`].
+///
+/// `writer.flush()` is not called after the copy completes.
+///
+/// ```
+/// use embedded_io::Cursor;
+///
+/// let mut reader = Cursor::new(b"hello");
+/// let mut buf = [0u8; 8];
+/// let mut writer = Cursor::new(&mut buf[..]);
+///
+/// let n = embedded_io::copy(&mut reader, &mut writer).unwrap();
+/// assert_eq!(n, 5);
+/// assert_eq!(&buf[..5], b"hello");
+/// ```
+pub fn copy<R, W>(reader: &mut R, writer: &mut W) -> Result<u64, R::Error>
+where
+    R: Read + ?Sized,
+    W: Write<Error = R::Error> + ?Sized,
+{
+    let mut buf = [0; BUF_SIZE];
+    let mut copied = 0;
+    loop {
+        let n = reader.read(&mut buf)?;
+        if n == 0 {
+            return Ok(copied);
+        }
+        writer.write_all(&buf[..n])?;
+        copied += n as u64;
+    }
+}
+
+/// Copies at most `n` bytes from a reader into a writer.
+///
+/// This function stops after copying `n` bytes, or when `reader` reaches EOF, whichever comes
+/// first, and returns the number of bytes copied.
+///
+/// `writer.flush()` is not called after the copy completes.
+pub fn copy_n<R, W>(reader: &mut R, writer: &mut W, n: u64) -> Result<u64, R::Error>
+where
+    R: Read + ?Sized,
+    W: Write<Error = R::Error> + ?Sized,
+{
+    copy(&mut reader.take(n), writer)
+}
@@ -0,0 +1,171 @@
+use crate::{BufRead, ErrorType, Read, ReadReady, Write, WriteReady};
+
+#[cfg(feature = "defmt-03")]
+use crate::defmt;
+
+/// Reader adapter which counts the bytes read from an underlying reader.
+///
+/// This struct is generally created by calling [`counting`](Read::counting) on a reader.
+///
+/// ```
+/// # use embedded_io::Read;
+/// let mut reader = Read::counting(&b"hello world"[..]);
+/// let mut buf = [0; 5];
+/// reader.read_exact(&mut buf).unwrap();
+/// assert_eq!(reader.bytes_read(), 5);
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub struct CountingReader<R> {
+    inner: R,
+    count: u64,
+}
+
+impl<R> CountingReader<R> {
+    pub(crate) fn new(inner: R) -> Self {
+        Self { inner, count: 0 }
+    }
+
+    /// Returns the number of bytes read since this instance was created or last reset.
+    #[inline]
+    pub fn bytes_read(&self) -> u64 {
+        self.count
+    }
+
+    /// Resets the number of bytes read to zero.
+    #[inline]
+    pub fn reset(&mut self) {
+        self.count = 0;
+    }
+
+    /// Consumes the `CountingReader`, returning the wrapped reader.
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    /// Gets a reference to the underlying reader.
+    #[inline]
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the underlying reader.
+    ///
+    /// Bytes read directly from the underlying reader are not counted.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+}
+
+impl<R: ErrorType> ErrorType for CountingReader<R> {
+    type Error = R::Error;
+}
+
+impl<R: Read> Read for CountingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        let n = self.inner.read(buf)?;
+        self.count += n as u64;
+        Ok(n)
+    }
+}
+
+impl<R: BufRead> BufRead for CountingReader<R> {
+    #[inline]
+    fn fill_buf(&mut self) -> Result<&[u8], Self::Error> {
+        self.inner.fill_buf()
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.count += amt as u64;
+        self.inner.consume(amt);
+    }
+}
+
+impl<R: ReadReady> ReadReady for CountingReader<R> {
+    #[inline]
+    fn read_ready(&mut self) -> Result<bool, Self::Error> {
+        self.inner.read_ready()
+    }
+}
+
+/// Writer adapter which counts the bytes written to an underlying writer.
+///
+/// This struct is generally created by calling [`counting`](Write::counting) on a writer.
+///
+/// ```
+/// # use embedded_io::Write;
+/// let mut buf = [0u8; 16];
+/// let mut writer = Write::counting(&mut buf[..]);
+/// writer.write_all(b"hello").unwrap();
+/// assert_eq!(writer.bytes_written(), 5);
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub struct CountingWriter<W> {
+    inner: W,
+    count: u64,
+}
+
+impl<W> CountingWriter<W> {
+    pub(crate) fn new(inner: W) -> Self {
+        Self { inner, count: 0 }
+    }
+
+    /// Returns the number of bytes written since this instance was created or last reset.
+    #[inline]
+    pub fn bytes_written(&self) -> u64 {
+        self.count
+    }
+
+    /// Resets the number of bytes written to zero.
+    #[inline]
+    pub fn reset(&mut self) {
+        self.count = 0;
+    }
+
+    /// Consumes the `CountingWriter`, returning the wrapped writer.
+    #[inline]
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+
+    /// Gets a reference to the underlying writer.
+    #[inline]
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the underlying writer.
+    ///
+    /// Bytes written directly to the underlying writer are not counted.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+}
+
+impl<W: ErrorType> ErrorType for CountingWriter<W> {
+    type Error = W::Error;
+}
+
+impl<W: Write> Write for CountingWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+        let n = self.inner.write(buf)?;
+        self.count += n as u64;
+        Ok(n)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        self.inner.flush()
+    }
+}
+
+impl<W: WriteReady> WriteReady for CountingWriter<W> {
+    #[inline]
+    fn write_ready(&mut self) -> Result<bool, Self::Error> {
+        self.inner.write_ready()
+    }
+}
@@ -0,0 +1,127 @@
+use crate::{ErrorType, Write};
+
+/// CRC algorithm, used by [`CrcWriter`].
+///
+/// This allows using any CRC implementation, e.g. from the `crc` crate or a hardware CRC unit,
+/// without `embedded-io` depending on it.
+pub trait CrcAlgorithm {
+    /// Size of the CRC value, in bytes, from 1 to 4.
+    ///
+    /// Creating a [`CrcWriter`] with a size out of this range fails to compile.
+    const SIZE: usize;
+
+    /// Feed `data` to the CRC computation.
+    fn update(&mut self, data: &[u8]);
+
+    /// Returns the CRC value of the data fed so far.
+    ///
+    /// Only the low [`SIZE`](CrcAlgorithm::SIZE) bytes are significant.
+    fn result(&self) -> u32;
+}
+
+/// Writer adapter which computes a CRC over the bytes written to an underlying writer.
+///
+/// Once the payload has been written, [`finalize_and_write`](CrcWriter::finalize_and_write)
+/// appends the CRC value, which is convenient for protocol framing.
+///
+/// ```
+/// # use embedded_io::{CrcAlgorithm, CrcWriter, Write};
+/// /// Simple additive checksum, for the example.
+/// struct Sum8(u8);
+///
+/// impl CrcAlgorithm for Sum8 {
+///     const SIZE: usize = 1;
+///
+///     fn update(&mut self, data: &[u8]) {
+///         for b in data {
+///             self.0 = self.0.wrapping_add(*b);
+///         }
+///     }
+///
+///     fn result(&self) -> u32 {
+///         self.0.into()
+///     }
+/// }
+///
+/// let mut buf = [0u8; 4];
+/// let mut writer = CrcWriter::new(&mut buf[..], Sum8(0));
+/// writer.write_all(&[1, 2, 3]).unwrap();
+/// assert_eq!(writer.crc_value(), 6);
+/// writer.finalize_and_write().unwrap();
+/// assert_eq!(buf, [1, 2, 3, 6]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct CrcWriter<W, C> {
+    inner: W,
+    crc: C,
+}
+
+impl<W, C: CrcAlgorithm> CrcWriter<W, C> {
+    /// Creates a new `CrcWriter`, computing the CRC of the written bytes with `crc`.
+    #[inline]
+    pub fn new(inner: W, crc: C) -> Self {
+        const {
+            assert!(
+                C::SIZE >= 1 && C::SIZE <= 4,
+                "CRC size must be from 1 to 4 bytes"
+            )
+        }
+        Self { inner, crc }
+    }
+
+    /// Returns the CRC value of the bytes written so far.
+    #[inline]
+    pub fn crc_value(&self) -> u32 {
+        self.crc.result()
+    }
+
+    /// Gets a reference to the underlying writer.
+    #[inline]
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the underlying writer.
+    ///
+    /// Bytes written directly to the underlying writer are not included in the CRC.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Consumes the `CrcWriter`, returning the wrapped writer without writing the CRC value.
+    #[inline]
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<W: Write, C: CrcAlgorithm> CrcWriter<W, C> {
+    /// Writes the CRC value to the underlying writer, and returns it.
+    ///
+    /// The [`SIZE`](CrcAlgorithm::SIZE) low bytes of the CRC value are written most significant
+    /// byte first. For protocols sending the CRC least significant byte first, write
+    /// [`crc_value`](CrcWriter::crc_value) with e.g. [`Write::write_u16_le`] instead.
+    pub fn finalize_and_write(mut self) -> Result<W, W::Error> {
+        let bytes = self.crc.result().to_be_bytes();
+        self.inner.write_all(&bytes[bytes.len() - C::SIZE..])?;
+        Ok(self.inner)
+    }
+}
+
+impl<W: ErrorType, C> ErrorType for CrcWriter<W, C> {
+    type Error = W::Error;
+}
+
+impl<W: Write, C: CrcAlgorithm> Write for CrcWriter<W, C> {
+    fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+        let n = self.inner.write(buf)?;
+        self.crc.update(&buf[..n]);
+        Ok(n)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        self.inner.flush()
+    }
+}
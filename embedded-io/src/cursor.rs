@@ -0,0 +1,217 @@
+use core::cmp;
+
+use crate::{BufRead, ErrorKind, ErrorType, Read, Seek, SeekFrom, Write};
+
+#[cfg(feature = "defmt-03")]
+use crate::defmt;
+
+/// A `Cursor` wraps an in-memory buffer and provides it with a [`Seek`] implementation.
+///
+/// This is the `embedded-io` equivalent of [`std::io::Cursor`].
+///
+/// `Cursor`s are used with in-memory buffers, anything implementing `AsRef<[u8]>`,
+/// to allow them to implement [`Read`] and [`BufRead`]. `Cursor<&mut [u8]>` also implements [`Write`],
+/// as does `Cursor<Vec<u8>>` with the `alloc` feature.
+///
+/// ```rust
+/// # use embedded_io::{Cursor, Read};
+/// let mut cursor = Cursor::new([1u8, 2, 3, 4, 5]);
+/// let mut buf = [0u8; 2];
+/// cursor.read_exact(&mut buf).unwrap();
+///
+/// assert_eq!(cursor.bytes_read(), 2);
+/// assert_eq!(cursor.remaining(), 3);
+/// assert_eq!(cursor.remaining_slice(), &[3, 4, 5]);
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub struct Cursor<T> {
+    inner: T,
+    pos: u64,
+}
+
+impl<T> Cursor<T> {
+    /// Creates a new cursor wrapping the provided underlying in-memory buffer.
+    ///
+    /// The initial position of the cursor is `0`.
+    #[inline]
+    pub const fn new(inner: T) -> Self {
+        Self { inner, pos: 0 }
+    }
+
+    /// Consumes this cursor, returning the underlying value.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+
+    /// Gets a reference to the underlying value in this cursor.
+    #[inline]
+    pub const fn get_ref(&self) -> &T {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the underlying value in this cursor.
+    ///
+    /// Care should be taken to avoid modifying the internal I/O state of the
+    /// underlying value as it may corrupt this cursor's position.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut T {
+        &mut self.inner
+    }
+
+    /// Returns the current position of this cursor.
+    #[inline]
+    pub const fn position(&self) -> u64 {
+        self.pos
+    }
+
+    /// Sets the position of this cursor.
+    #[inline]
+    pub fn set_position(&mut self, pos: u64) {
+        self.pos = pos;
+    }
+}
+
+impl<T: AsRef<[u8]>> Cursor<T> {
+    /// Returns the remaining slice, from the current position to the end of the buffer.
+    ///
+    /// If the position is past the end of the buffer, an empty slice is returned.
+    #[inline]
+    pub fn remaining_slice(&self) -> &[u8] {
+        let inner = self.inner.as_ref();
+        let start = cmp::min(self.pos, inner.len() as u64) as usize;
+        &inner[start..]
+    }
+
+    /// Returns the number of bytes remaining, from the current position to the end of the buffer.
+    ///
+    /// This is equivalent to `remaining_slice().len()`. If the position is past the end of the
+    /// buffer, `0` is returned.
+    #[inline]
+    pub fn remaining(&self) -> u64 {
+        self.remaining_slice().len() as u64
+    }
+
+    /// Returns `true` if the remaining slice is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.remaining_slice().is_empty()
+    }
+
+    /// Returns the number of bytes read so far.
+    ///
+    /// This is a synonym for [`position`](Cursor::position).
+    #[inline]
+    pub fn bytes_read(&self) -> u64 {
+        self.pos
+    }
+}
+
+impl Cursor<&mut [u8]> {
+    /// Returns the number of bytes written so far.
+    ///
+    /// This is a synonym for [`position`](Cursor::position).
+    #[inline]
+    pub fn bytes_written(&self) -> u64 {
+        self.pos
+    }
+}
+
+impl<T> ErrorType for Cursor<T> {
+    type Error = ErrorKind;
+}
+
+impl<T: AsRef<[u8]>> Read for Cursor<T> {
+    #[inline]
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        let mut remaining = self.remaining_slice();
+        let n = match Read::read(&mut remaining, buf) {
+            Ok(n) => n,
+            Err(e) => match e {},
+        };
+        self.pos += n as u64;
+        Ok(n)
+    }
+}
+
+impl<T: AsRef<[u8]>> BufRead for Cursor<T> {
+    #[inline]
+    fn fill_buf(&mut self) -> Result<&[u8], Self::Error> {
+        Ok(self.remaining_slice())
+    }
+
+    #[inline]
+    fn consume(&mut self, amt: usize) {
+        self.pos += amt as u64;
+    }
+}
+
+impl<T: AsRef<[u8]>> Seek for Cursor<T> {
+    fn seek(&mut self, pos: SeekFrom) -> Result<u64, Self::Error> {
+        let (base, offset) = match pos {
+            SeekFrom::Start(n) => {
+                self.pos = n;
+                return Ok(n);
+            }
+            SeekFrom::End(n) => (self.inner.as_ref().len() as u64, n),
+            SeekFrom::Current(n) => (self.pos, n),
+        };
+        match base.checked_add_signed(offset) {
+            Some(n) => {
+                self.pos = n;
+                Ok(n)
+            }
+            None => Err(ErrorKind::InvalidInput),
+        }
+    }
+}
+
+/// Write is implemented for `Cursor<&mut [u8]>` by copying into the slice at the current position,
+/// overwriting its data.
+///
+/// If the cursor is at the end of the slice, write operations return an error of kind
+/// [`ErrorKind::WriteZero`].
+impl Write for Cursor<&mut [u8]> {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+        let start = cmp::min(self.pos, self.inner.len() as u64) as usize;
+        let amt = cmp::min(buf.len(), self.inner.len() - start);
+        if !buf.is_empty() && amt == 0 {
+            return Err(ErrorKind::WriteZero);
+        }
+        self.inner[start..start + amt].copy_from_slice(&buf[..amt]);
+        self.pos += amt as u64;
+        Ok(amt)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+/// Write is implemented for `Cursor<Vec<u8>>` by overwriting the vector at the current position,
+/// growing it as needed.
+///
+/// If the position is past the end of the vector, the gap is filled with zeros.
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "std", feature = "alloc"))))]
+impl Write for Cursor<alloc::vec::Vec<u8>> {
+    fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+        let pos = usize::try_from(self.pos).map_err(|_| ErrorKind::OutOfMemory)?;
+        if self.inner.len() < pos {
+            self.inner.resize(pos, 0);
+        }
+        let overlap = cmp::min(buf.len(), self.inner.len() - pos);
+        self.inner[pos..pos + overlap].copy_from_slice(&buf[..overlap]);
+        self.inner.extend_from_slice(&buf[overlap..]);
+        self.pos += buf.len() as u64;
+        Ok(buf.len())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
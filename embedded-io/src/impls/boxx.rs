@@ -1,4 +1,4 @@
-use crate::{BufRead, ErrorType, Read, ReadReady, Seek, Write, WriteReady};
+use crate::{BufRead, ErrorType, IoSlice, Read, ReadReady, Seek, Write, WriteReady};
 use alloc::boxed::Box;
 
 #[cfg_attr(docsrs, doc(cfg(any(feature = "std", feature = "alloc"))))]
@@ -36,6 +36,11 @@ impl<T: ?Sized + Write> Write for Box<T> {
     fn flush(&mut self) -> Result<(), Self::Error> {
         T::flush(self)
     }
+
+    #[inline]
+    fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> Result<usize, Self::Error> {
+        T::write_vectored(self, bufs)
+    }
 }
 
 #[cfg_attr(docsrs, doc(cfg(any(feature = "std", feature = "alloc"))))]
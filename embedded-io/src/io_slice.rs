@@ -0,0 +1,64 @@
+use core::ops::Deref;
+
+#[cfg(feature = "defmt-03")]
+use crate::defmt;
+
+/// A buffer to be written as part of a vectored write.
+///
+/// This is the `embedded-io` equivalent of [`std::io::IoSlice`]. It dereferences to `[u8]`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub struct IoSlice<'a>(&'a [u8]);
+
+impl<'a> IoSlice<'a> {
+    /// Creates a new `IoSlice` wrapping a byte slice.
+    #[inline]
+    pub const fn new(buf: &'a [u8]) -> Self {
+        Self(buf)
+    }
+
+    /// Advances the slice by `n` bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is greater than the length of the slice.
+    #[inline]
+    pub fn advance(&mut self, n: usize) {
+        self.0 = &self.0[n..];
+    }
+
+    /// Advances a slice of slices by `n` bytes.
+    ///
+    /// Fully consumed slices are removed from `bufs`, and the first remaining one is advanced
+    /// by the rest of `n`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is greater than the total length of the slices.
+    pub fn advance_slices(bufs: &mut &mut [IoSlice<'a>], n: usize) {
+        let mut remaining = n;
+        let mut removed = 0;
+        for buf in bufs.iter() {
+            if buf.len() > remaining {
+                break;
+            }
+            remaining -= buf.len();
+            removed += 1;
+        }
+
+        *bufs = &mut core::mem::take(bufs)[removed..];
+        match bufs.first_mut() {
+            Some(first) => first.advance(remaining),
+            None => assert!(remaining == 0, "advancing IoSlices beyond their length"),
+        }
+    }
+}
+
+impl Deref for IoSlice<'_> {
+    type Target = [u8];
+
+    #[inline]
+    fn deref(&self) -> &[u8] {
+        self.0
+    }
+}
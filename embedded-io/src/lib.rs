@@ -12,7 +12,29 @@ use defmt_03 as defmt;
 #[cfg(feature = "alloc")]
 extern crate alloc;
 
+mod copy;
+mod counting;
+#[cfg(feature = "crc")]
+mod crc;
+mod cursor;
 mod impls;
+mod io_slice;
+mod limited;
+mod pipe;
+mod pipeline;
+mod take;
+
+pub use copy::{copy, copy_n};
+pub use counting::{CountingReader, CountingWriter};
+#[cfg(feature = "crc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "crc")))]
+pub use crc::{CrcAlgorithm, CrcWriter};
+pub use cursor::Cursor;
+pub use io_slice::IoSlice;
+pub use limited::{Limited, LimitedWriteError};
+pub use pipe::{Pipe, PipeReader, PipeWriter};
+pub use pipeline::Pipeline;
+pub use take::Take;
 
 /// Enumeration of possible methods to seek within an I/O object.
 ///
@@ -265,7 +287,43 @@ impl<E: fmt::Debug> fmt::Display for ReadExactError<E> {
 
 impl<E: fmt::Debug> core::error::Error for ReadExactError<E> {}
 
-/// Errors that could be returned by `Write` on `&mut [u8]`.
+/// Error returned by [`Read::read_exact_partial`].
+///
+/// Unlike [`ReadExactError`], this reports how many bytes were read before the error. This allows
+/// telling a clean EOF between messages, with no byte read, from a truncated message.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub struct ReadExactPartial<E> {
+    /// Number of bytes read at the beginning of the buffer before the error.
+    pub bytes_read: usize,
+    /// Cause of the error.
+    pub source: ReadExactError<E>,
+}
+
+impl<E> ReadExactPartial<E> {
+    /// Returns `true` if EOF was reached before reading any byte.
+    #[inline]
+    pub fn is_clean_eof(&self) -> bool {
+        self.bytes_read == 0 && matches!(self.source, ReadExactError::UnexpectedEof)
+    }
+}
+
+impl<E> From<ReadExactPartial<E>> for ReadExactError<E> {
+    #[inline]
+    fn from(err: ReadExactPartial<E>) -> Self {
+        err.source
+    }
+}
+
+impl<E: fmt::Debug> fmt::Display for ReadExactPartial<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+impl<E: fmt::Debug> core::error::Error for ReadExactPartial<E> {}
+
+/// Errors that could be returned by `Write` on `&mut [u8]` and other fixed-size buffers, such as [`Pipe`].
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 #[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
 #[non_exhaustive]
@@ -354,6 +412,172 @@ pub trait Read: ErrorType {
             Err(ReadExactError::UnexpectedEof)
         }
     }
+
+    /// Read the exact number of bytes required to fill `buf`, reporting how many bytes were read
+    /// on error.
+    ///
+    /// This behaves like [`read_exact`](Read::read_exact), but the error reports the number of
+    /// bytes read at the beginning of `buf` before EOF or an error of the inner reader. This is
+    /// useful when decoding a stream of messages, to tell a clean end of stream from a truncated
+    /// message:
+    ///
+    /// ```
+    /// # use embedded_io::Read;
+    /// let mut stream: &[u8] = b"abcdef";
+    /// let mut message = [0; 4];
+    /// stream.read_exact_partial(&mut message).unwrap();
+    ///
+    /// let err = stream.read_exact_partial(&mut message).unwrap_err();
+    /// assert_eq!(err.bytes_read, 2);
+    /// assert!(!err.is_clean_eof());
+    ///
+    /// let err = stream.read_exact_partial(&mut message).unwrap_err();
+    /// assert!(err.is_clean_eof());
+    /// ```
+    fn read_exact_partial(&mut self, buf: &mut [u8]) -> Result<(), ReadExactPartial<Self::Error>> {
+        let mut bytes_read = 0;
+        while bytes_read < buf.len() {
+            match self.read(&mut buf[bytes_read..]) {
+                Ok(0) => {
+                    return Err(ReadExactPartial {
+                        bytes_read,
+                        source: ReadExactError::UnexpectedEof,
+                    })
+                }
+                Ok(n) => bytes_read += n,
+                Err(e) => {
+                    return Err(ReadExactPartial {
+                        bytes_read,
+                        source: ReadExactError::Other(e),
+                    })
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Read all bytes until EOF, appending them to `buf`.
+    ///
+    /// This function calls `read()` in a loop until it returns `Ok(0)`, blocking if needed.
+    /// The number of bytes appended to `buf` is returned.
+    ///
+    /// `buf` is grown in fixed chunks of 64 bytes, and truncated to the amount of data actually
+    /// read after each call to `read()`.
+    ///
+    /// If an error is returned, all bytes read so far are kept in `buf`.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "std", feature = "alloc"))))]
+    fn read_to_end(&mut self, buf: &mut alloc::vec::Vec<u8>) -> Result<usize, Self::Error> {
+        const CHUNK_SIZE: usize = 64;
+
+        let start_len = buf.len();
+        loop {
+            let len = buf.len();
+            buf.resize(len + CHUNK_SIZE, 0);
+            match self.read(&mut buf[len..]) {
+                Ok(0) => {
+                    buf.truncate(len);
+                    return Ok(len - start_len);
+                }
+                Ok(n) => buf.truncate(len + n),
+                Err(e) => {
+                    buf.truncate(len);
+                    return Err(e);
+                }
+            }
+        }
+    }
+
+    /// Creates an adapter which will read at most `limit` bytes from this reader.
+    ///
+    /// This function returns a new instance of `Read` which will read at most
+    /// `limit` bytes, after which it will always return EOF (`Ok(0)`).
+    fn take(self, limit: u64) -> Take<Self>
+    where
+        Self: Sized,
+    {
+        Take::new(self, limit)
+    }
+
+    /// Creates an adapter which counts the bytes read from this reader.
+    ///
+    /// See [`CountingReader`].
+    fn counting(self) -> CountingReader<Self>
+    where
+        Self: Sized,
+    {
+        CountingReader::new(self)
+    }
+
+    /// Read a `u8`.
+    ///
+    /// This calls [`read_exact`](Read::read_exact), blocking if needed.
+    #[inline]
+    fn read_u8(&mut self) -> Result<u8, ReadExactError<Self::Error>> {
+        let mut buf = [0; 1];
+        self.read_exact(&mut buf)?;
+        Ok(buf[0])
+    }
+
+    /// Read a little-endian `u16`.
+    ///
+    /// This calls [`read_exact`](Read::read_exact), blocking if needed.
+    #[inline]
+    fn read_u16_le(&mut self) -> Result<u16, ReadExactError<Self::Error>> {
+        let mut buf = [0; 2];
+        self.read_exact(&mut buf)?;
+        Ok(u16::from_le_bytes(buf))
+    }
+
+    /// Read a big-endian `u16`.
+    ///
+    /// This calls [`read_exact`](Read::read_exact), blocking if needed.
+    #[inline]
+    fn read_u16_be(&mut self) -> Result<u16, ReadExactError<Self::Error>> {
+        let mut buf = [0; 2];
+        self.read_exact(&mut buf)?;
+        Ok(u16::from_be_bytes(buf))
+    }
+
+    /// Read a little-endian `u32`.
+    ///
+    /// This calls [`read_exact`](Read::read_exact), blocking if needed.
+    #[inline]
+    fn read_u32_le(&mut self) -> Result<u32, ReadExactError<Self::Error>> {
+        let mut buf = [0; 4];
+        self.read_exact(&mut buf)?;
+        Ok(u32::from_le_bytes(buf))
+    }
+
+    /// Read a big-endian `u32`.
+    ///
+    /// This calls [`read_exact`](Read::read_exact), blocking if needed.
+    #[inline]
+    fn read_u32_be(&mut self) -> Result<u32, ReadExactError<Self::Error>> {
+        let mut buf = [0; 4];
+        self.read_exact(&mut buf)?;
+        Ok(u32::from_be_bytes(buf))
+    }
+
+    /// Read a little-endian `u64`.
+    ///
+    /// This calls [`read_exact`](Read::read_exact), blocking if needed.
+    #[inline]
+    fn read_u64_le(&mut self) -> Result<u64, ReadExactError<Self::Error>> {
+        let mut buf = [0; 8];
+        self.read_exact(&mut buf)?;
+        Ok(u64::from_le_bytes(buf))
+    }
+
+    /// Read a big-endian `u64`.
+    ///
+    /// This calls [`read_exact`](Read::read_exact), blocking if needed.
+    #[inline]
+    fn read_u64_be(&mut self) -> Result<u64, ReadExactError<Self::Error>> {
+        let mut buf = [0; 8];
+        self.read_exact(&mut buf)?;
+        Ok(u64::from_be_bytes(buf))
+    }
 }
 
 /// Blocking buffered reader.
@@ -371,6 +595,50 @@ pub trait BufRead: ErrorType {
 
     /// Tell this buffer that `amt` bytes have been consumed from the buffer, so they should no longer be returned in calls to `fill_buf`.
     fn consume(&mut self, amt: usize);
+
+    /// Read bytes into `buf` until the delimiter `byte` or EOF is reached, or `buf` is full.
+    ///
+    /// If the delimiter is found, it is included in `buf`. Returns the number of bytes read.
+    /// If the returned amount is `buf.len()` and the last byte isn't the delimiter, `buf` was too
+    /// small to hold the entire data up to the delimiter, and the rest can be read with another call.
+    ///
+    /// This function blocks until the delimiter is found, EOF is reached, or `buf` is full.
+    ///
+    /// ```
+    /// # use embedded_io::BufRead;
+    /// let mut reader: &[u8] = b"AT\r\nOK\r\n";
+    /// let mut buf = [0u8; 16];
+    /// let n = reader.read_until(b'\n', &mut buf).unwrap();
+    /// assert_eq!(&buf[..n], b"AT\r\n");
+    /// ```
+    fn read_until(&mut self, byte: u8, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        let mut read = 0;
+        while read < buf.len() {
+            let available = self.fill_buf()?;
+            if available.is_empty() {
+                break;
+            }
+            let len = core::cmp::min(available.len(), buf.len() - read);
+            let (found, used) = match available[..len].iter().position(|&b| b == byte) {
+                Some(i) => (true, i + 1),
+                None => (false, len),
+            };
+            buf[read..read + used].copy_from_slice(&available[..used]);
+            self.consume(used);
+            read += used;
+            if found {
+                break;
+            }
+        }
+        Ok(read)
+    }
+
+    /// Read a line into `buf`.
+    ///
+    /// This is equivalent to `read_until(b'\n', buf)`: the newline is included in `buf` if found.
+    fn read_line(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        self.read_until(b'\n', buf)
+    }
 }
 
 /// Blocking writer.
@@ -398,6 +666,19 @@ pub trait Write: ErrorType {
     /// Flush this output stream, blocking until all intermediately buffered contents reach their destination.
     fn flush(&mut self) -> Result<(), Self::Error>;
 
+    /// Like [`write`](Write::write), except that it writes from a slice of buffers.
+    ///
+    /// Data is copied from each buffer in order, with the final buffer read from possibly being
+    /// only partially consumed. Returns the total number of bytes written.
+    ///
+    /// The default implementation calls `write()` with the first non-empty buffer. Implementations
+    /// able to write several buffers in a single operation, such as with scatter-gather DMA,
+    /// should override it.
+    fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> Result<usize, Self::Error> {
+        let buf = bufs.iter().find(|b| !b.is_empty()).map_or(&[][..], |b| b);
+        self.write(buf)
+    }
+
     /// Write an entire buffer into this writer.
     ///
     /// This function calls `write()` in a loop until exactly `buf.len()` bytes have
@@ -419,6 +700,39 @@ pub trait Write: ErrorType {
         Ok(())
     }
 
+    /// Write all the buffers into this writer.
+    ///
+    /// This function calls `write_vectored()` in a loop until all the buffers have been written,
+    /// blocking if needed. `bufs` is modified during the operation, and its contents are
+    /// unspecified once this returns.
+    ///
+    /// If you are using [`WriteReady`] to avoid blocking, you should not use this function.
+    /// `WriteReady::write_ready()` returning true only guarantees the first call to `write()` will
+    /// not block, so this function may still block in subsequent calls.
+    ///
+    /// This function will panic if `write_vectored()` returns `Ok(0)` while data remains.
+    ///
+    /// ```
+    /// # use embedded_io::{IoSlice, Write};
+    /// let mut buf = [0u8; 8];
+    /// let mut writer = &mut buf[..];
+    /// let mut bufs = [IoSlice::new(b"abc"), IoSlice::new(b""), IoSlice::new(b"de")];
+    /// writer.write_all_vectored(&mut bufs).unwrap();
+    /// assert_eq!(&buf[..5], b"abcde");
+    /// ```
+    fn write_all_vectored(&mut self, mut bufs: &mut [IoSlice<'_>]) -> Result<(), Self::Error> {
+        // Skip leading empty buffers, so that an empty write is not mistaken for `Ok(0)`.
+        IoSlice::advance_slices(&mut bufs, 0);
+        while !bufs.is_empty() {
+            match self.write_vectored(bufs) {
+                Ok(0) => panic!("write_vectored() returned Ok(0)"),
+                Ok(n) => IoSlice::advance_slices(&mut bufs, n),
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+
     /// Write a formatted string into this writer, returning any error encountered.
     ///
     /// This function calls `write()` in a loop until the entire formatted string has
@@ -471,6 +785,83 @@ pub trait Write: ErrorType {
             },
         }
     }
+
+    /// Creates an adapter which will write at most `limit` bytes to this writer.
+    ///
+    /// Once `limit` bytes have been written, writes fail with an error of kind
+    /// [`ErrorKind::WriteZero`]. See [`Limited`].
+    fn limit(self, limit: u64) -> Limited<Self>
+    where
+        Self: Sized,
+    {
+        Limited::new(self, limit)
+    }
+
+    /// Creates an adapter which counts the bytes written to this writer.
+    ///
+    /// See [`CountingWriter`].
+    fn counting(self) -> CountingWriter<Self>
+    where
+        Self: Sized,
+    {
+        CountingWriter::new(self)
+    }
+
+    /// Write a `u8`.
+    ///
+    /// This calls [`write_all`](Write::write_all), blocking if needed.
+    #[inline]
+    fn write_u8(&mut self, v: u8) -> Result<(), Self::Error> {
+        self.write_all(&[v])
+    }
+
+    /// Write a little-endian `u16`.
+    ///
+    /// This calls [`write_all`](Write::write_all), blocking if needed.
+    #[inline]
+    fn write_u16_le(&mut self, v: u16) -> Result<(), Self::Error> {
+        self.write_all(&v.to_le_bytes())
+    }
+
+    /// Write a big-endian `u16`.
+    ///
+    /// This calls [`write_all`](Write::write_all), blocking if needed.
+    #[inline]
+    fn write_u16_be(&mut self, v: u16) -> Result<(), Self::Error> {
+        self.write_all(&v.to_be_bytes())
+    }
+
+    /// Write a little-endian `u32`.
+    ///
+    /// This calls [`write_all`](Write::write_all), blocking if needed.
+    #[inline]
+    fn write_u32_le(&mut self, v: u32) -> Result<(), Self::Error> {
+        self.write_all(&v.to_le_bytes())
+    }
+
+    /// Write a big-endian `u32`.
+    ///
+    /// This calls [`write_all`](Write::write_all), blocking if needed.
+    #[inline]
+    fn write_u32_be(&mut self, v: u32) -> Result<(), Self::Error> {
+        self.write_all(&v.to_be_bytes())
+    }
+
+    /// Write a little-endian `u64`.
+    ///
+    /// This calls [`write_all`](Write::write_all), blocking if needed.
+    #[inline]
+    fn write_u64_le(&mut self, v: u64) -> Result<(), Self::Error> {
+        self.write_all(&v.to_le_bytes())
+    }
+
+    /// Write a big-endian `u64`.
+    ///
+    /// This calls [`write_all`](Write::write_all), blocking if needed.
+    #[inline]
+    fn write_u64_be(&mut self, v: u64) -> Result<(), Self::Error> {
+        self.write_all(&v.to_be_bytes())
+    }
 }
 
 /// Blocking seek within streams.
@@ -546,6 +937,11 @@ impl<T: ?Sized + Write> Write for &mut T {
     fn flush(&mut self) -> Result<(), Self::Error> {
         T::flush(self)
     }
+
+    #[inline]
+    fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> Result<usize, Self::Error> {
+        T::write_vectored(self, bufs)
+    }
 }
 
 impl<T: ?Sized + Seek> Seek for &mut T {
@@ -0,0 +1,122 @@
+use core::{cmp, fmt};
+
+use crate::{Error, ErrorKind, ErrorType, Write};
+
+#[cfg(feature = "defmt-03")]
+use crate::defmt;
+
+/// Writer adapter which limits the bytes written to an underlying writer.
+///
+/// This struct is generally created by calling [`limit`](Write::limit) on a writer.
+/// Once `limit` bytes have been written, further writes fail with
+/// [`LimitedWriteError::LimitReached`], of kind [`ErrorKind::WriteZero`].
+///
+/// This is useful to generate protocol data units of an exact length, without
+/// having to buffer them first.
+///
+/// ```
+/// # use embedded_io::{ErrorKind, Error, Write};
+/// let mut buf = [0u8; 8];
+/// let mut writer = (&mut buf[..]).limit(4);
+/// writer.write_all(b"abcd").unwrap();
+/// assert_eq!(writer.bytes_remaining(), 0);
+/// assert_eq!(writer.write(b"e").unwrap_err().kind(), ErrorKind::WriteZero);
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub struct Limited<W> {
+    inner: W,
+    remaining: u64,
+}
+
+impl<W> Limited<W> {
+    pub(crate) fn new(inner: W, limit: u64) -> Self {
+        Self {
+            inner,
+            remaining: limit,
+        }
+    }
+
+    /// Returns the number of bytes that can still be written before the limit is reached.
+    #[inline]
+    pub fn bytes_remaining(&self) -> u64 {
+        self.remaining
+    }
+
+    /// Consumes the `Limited`, returning the wrapped writer.
+    #[inline]
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+
+    /// Gets a reference to the underlying writer.
+    #[inline]
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the underlying writer.
+    ///
+    /// Care should be taken to avoid modifying the internal I/O state of the
+    /// underlying writer as doing so may corrupt the internal limit of this `Limited`.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+}
+
+/// Error returned by [`Limited`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub enum LimitedWriteError<E> {
+    /// The write limit was reached, no more bytes can be written.
+    LimitReached,
+    /// Error returned by the inner Write.
+    Other(E),
+}
+
+impl<E> From<E> for LimitedWriteError<E> {
+    fn from(err: E) -> Self {
+        Self::Other(err)
+    }
+}
+
+impl<E: Error> Error for LimitedWriteError<E> {
+    fn kind(&self) -> ErrorKind {
+        match self {
+            Self::LimitReached => ErrorKind::WriteZero,
+            Self::Other(e) => e.kind(),
+        }
+    }
+}
+
+impl<E: fmt::Debug> fmt::Display for LimitedWriteError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+impl<E: fmt::Debug> core::error::Error for LimitedWriteError<E> {}
+
+impl<W: ErrorType> ErrorType for Limited<W> {
+    type Error = LimitedWriteError<W::Error>;
+}
+
+impl<W: Write> Write for Limited<W> {
+    fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+        if self.remaining == 0 {
+            return Err(LimitedWriteError::LimitReached);
+        }
+        let max = cmp::min(buf.len() as u64, self.remaining) as usize;
+        let n = self.inner.write(&buf[..max])?;
+        self.remaining -= n as u64;
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        Ok(self.inner.flush()?)
+    }
+}
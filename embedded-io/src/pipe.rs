@@ -0,0 +1,462 @@
+use core::cell::UnsafeCell;
+use core::cmp;
+use core::ptr;
+use core::sync::atomic::{AtomicUsize, Ordering};
+#[cfg(target_has_atomic = "ptr")]
+use core::task::{Context, Poll, Waker};
+
+use crate::{ErrorType, Read, ReadReady, SliceWriteError, Write, WriteReady};
+
+/// Fixed-size ring buffer, implementing both [`Read`] and [`Write`].
+///
+/// The capacity `N` must be a power of two: this is checked at compile time.
+///
+/// Bytes written to the pipe can be read back in the same order. Unlike most readers,
+/// [`read`](Read::read) returns `Ok(0)` when the pipe is empty, without blocking: this doesn't
+/// mean the end of the stream has been reached, more bytes can be written later. Writing to a
+/// full pipe fails with [`SliceWriteError::Full`].
+///
+/// The pipe can be [`split`](Pipe::split) into a [`PipeReader`] and a [`PipeWriter`], which can
+/// be used from different contexts, e.g. an interrupt handler and the main loop, or two tasks.
+/// `embedded-io-async` implements the async traits for them, on targets with atomic
+/// compare-and-swap.
+///
+/// ```
+/// # use embedded_io::{Pipe, Read, Write};
+/// let mut pipe = Pipe::<8>::new();
+/// pipe.write_all(b"hello").unwrap();
+/// assert_eq!(pipe.len(), 5);
+///
+/// let mut buf = [0; 8];
+/// let n = pipe.read(&mut buf).unwrap();
+/// assert_eq!(&buf[..n], b"hello");
+/// assert!(pipe.is_empty());
+/// ```
+pub struct Pipe<const N: usize> {
+    buf: UnsafeCell<[u8; N]>,
+    // Both positions are free-running, wrapping on overflow. The number of bytes in the pipe is
+    // their difference. Since `N` is a power of two, it divides `usize::MAX + 1`, so `pos % N`
+    // stays continuous when a position wraps.
+    read_pos: AtomicUsize,
+    write_pos: AtomicUsize,
+    // Task waiting for bytes to be written, and task waiting for bytes to be read.
+    #[cfg(target_has_atomic = "ptr")]
+    read_waker: WakerCell,
+    #[cfg(target_has_atomic = "ptr")]
+    write_waker: WakerCell,
+}
+
+// SAFETY: the buffer is only accessed through `&mut Pipe`, or through the single `PipeReader` and
+// `PipeWriter` returned by `split`. The reader only accesses the bytes between `read_pos` and
+// `write_pos`, the writer only the other ones, and each only publishes its position after it is
+// done with the bytes it covers.
+unsafe impl<const N: usize> Sync for Pipe<N> {}
+
+impl<const N: usize> Pipe<N> {
+    /// Creates a new empty pipe.
+    pub const fn new() -> Self {
+        const {
+            assert!(
+                N.is_power_of_two(),
+                "the capacity of a `Pipe` must be a power of two"
+            )
+        }
+        Self {
+            buf: UnsafeCell::new([0; N]),
+            read_pos: AtomicUsize::new(0),
+            write_pos: AtomicUsize::new(0),
+            #[cfg(target_has_atomic = "ptr")]
+            read_waker: WakerCell::new(),
+            #[cfg(target_has_atomic = "ptr")]
+            write_waker: WakerCell::new(),
+        }
+    }
+
+    /// Returns the number of bytes in the pipe, that can be read.
+    #[inline]
+    pub fn len(&self) -> usize {
+        // `read_pos` is loaded first, so it can't be ahead of `write_pos`. If the pipe is used
+        // concurrently, the writer may have written more bytes in the meantime than the reader
+        // has freed, hence the clamp.
+        let read_pos = self.read_pos.load(Ordering::Acquire);
+        let write_pos = self.write_pos.load(Ordering::Acquire);
+        cmp::min(write_pos.wrapping_sub(read_pos), N)
+    }
+
+    /// Returns `true` if the pipe is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns `true` if the pipe is full.
+    #[inline]
+    pub fn is_full(&self) -> bool {
+        self.len() == N
+    }
+
+    /// Returns the number of bytes the pipe can hold.
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Splits the pipe into a reader and a writer.
+    ///
+    /// They can be moved to different contexts, and used concurrently.
+    #[inline]
+    pub fn split(&mut self) -> (PipeReader<'_, N>, PipeWriter<'_, N>) {
+        (PipeReader { pipe: self }, PipeWriter { pipe: self })
+    }
+
+    /// Reads bytes into `buf`. Must only be called by the single reader.
+    fn read_inner(&self, buf: &mut [u8]) -> usize {
+        let read_pos = self.read_pos.load(Ordering::Relaxed);
+        let write_pos = self.write_pos.load(Ordering::Acquire);
+        let n = cmp::min(write_pos.wrapping_sub(read_pos), buf.len());
+        if n == 0 {
+            return 0;
+        }
+
+        let start = read_pos % N;
+        let first = cmp::min(n, N - start);
+        let data = self.buf.get().cast::<u8>();
+        // SAFETY: the bytes between `read_pos` and `write_pos` are not accessed by the writer.
+        unsafe {
+            ptr::copy_nonoverlapping(data.add(start), buf.as_mut_ptr(), first);
+            ptr::copy_nonoverlapping(data, buf.as_mut_ptr().add(first), n - first);
+        }
+
+        self.read_pos
+            .store(read_pos.wrapping_add(n), Ordering::Release);
+        #[cfg(target_has_atomic = "ptr")]
+        self.write_waker.wake();
+        n
+    }
+
+    /// Writes bytes from `buf`. Must only be called by the single writer.
+    fn write_inner(&self, buf: &[u8]) -> usize {
+        let write_pos = self.write_pos.load(Ordering::Relaxed);
+        let read_pos = self.read_pos.load(Ordering::Acquire);
+        let n = cmp::min(N - write_pos.wrapping_sub(read_pos), buf.len());
+        if n == 0 {
+            return 0;
+        }
+
+        let start = write_pos % N;
+        let first = cmp::min(n, N - start);
+        let data = self.buf.get().cast::<u8>();
+        // SAFETY: the bytes outside of `read_pos..write_pos` are not accessed by the reader.
+        unsafe {
+            ptr::copy_nonoverlapping(buf.as_ptr(), data.add(start), first);
+            ptr::copy_nonoverlapping(buf.as_ptr().add(first), data, n - first);
+        }
+
+        self.write_pos
+            .store(write_pos.wrapping_add(n), Ordering::Release);
+        #[cfg(target_has_atomic = "ptr")]
+        self.read_waker.wake();
+        n
+    }
+
+    fn write_checked(&self, buf: &[u8]) -> Result<usize, SliceWriteError> {
+        match self.write_inner(buf) {
+            0 if !buf.is_empty() => Err(SliceWriteError::Full),
+            n => Ok(n),
+        }
+    }
+}
+
+impl<const N: usize> Default for Pipe<N> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> core::fmt::Debug for Pipe<N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Pipe")
+            .field("len", &self.len())
+            .field("capacity", &N)
+            .finish()
+    }
+}
+
+impl<const N: usize> ErrorType for Pipe<N> {
+    type Error = SliceWriteError;
+}
+
+impl<const N: usize> Read for Pipe<N> {
+    #[inline]
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        Ok(self.read_inner(buf))
+    }
+}
+
+impl<const N: usize> ReadReady for Pipe<N> {
+    #[inline]
+    fn read_ready(&mut self) -> Result<bool, Self::Error> {
+        Ok(true)
+    }
+}
+
+impl<const N: usize> Write for Pipe<N> {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+        self.write_checked(buf)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+impl<const N: usize> WriteReady for Pipe<N> {
+    #[inline]
+    fn write_ready(&mut self) -> Result<bool, Self::Error> {
+        Ok(true)
+    }
+}
+
+/// Reading half of a [`Pipe`], created by [`Pipe::split`].
+///
+/// Same as for the pipe, [`read`](Read::read) returns `Ok(0)` when the pipe is empty.
+#[derive(Debug)]
+pub struct PipeReader<'a, const N: usize> {
+    pipe: &'a Pipe<N>,
+}
+
+impl<const N: usize> PipeReader<'_, N> {
+    /// Returns the number of bytes in the pipe, that can be read.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.pipe.len()
+    }
+
+    /// Returns `true` if the pipe is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.pipe.is_empty()
+    }
+}
+
+#[cfg(target_has_atomic = "ptr")]
+impl<const N: usize> PipeReader<'_, N> {
+    /// Attempts to read bytes into `buf`.
+    ///
+    /// If the pipe is empty and `buf` isn't, this returns `Poll::Pending`, and the task is woken
+    /// once bytes have been written. Only the task of the last call is woken.
+    pub fn poll_read(&mut self, cx: &mut Context<'_>, buf: &mut [u8]) -> Poll<usize> {
+        let n = self.pipe.read_inner(buf);
+        if n != 0 || buf.is_empty() {
+            return Poll::Ready(n);
+        }
+        self.pipe.read_waker.register(cx.waker());
+        // Bytes may have been written before the waker was registered.
+        match self.pipe.read_inner(buf) {
+            0 => Poll::Pending,
+            n => Poll::Ready(n),
+        }
+    }
+}
+
+impl<const N: usize> ErrorType for PipeReader<'_, N> {
+    type Error = SliceWriteError;
+}
+
+impl<const N: usize> Read for PipeReader<'_, N> {
+    #[inline]
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        Ok(self.pipe.read_inner(buf))
+    }
+}
+
+impl<const N: usize> ReadReady for PipeReader<'_, N> {
+    #[inline]
+    fn read_ready(&mut self) -> Result<bool, Self::Error> {
+        Ok(true)
+    }
+}
+
+/// Writing half of a [`Pipe`], created by [`Pipe::split`].
+///
+/// Same as for the pipe, writing to a full pipe fails with [`SliceWriteError::Full`].
+#[derive(Debug)]
+pub struct PipeWriter<'a, const N: usize> {
+    pipe: &'a Pipe<N>,
+}
+
+impl<const N: usize> PipeWriter<'_, N> {
+    /// Returns `true` if the pipe is full.
+    #[inline]
+    pub fn is_full(&self) -> bool {
+        self.pipe.is_full()
+    }
+
+    /// Returns the number of bytes that can be written before the pipe is full.
+    #[inline]
+    pub fn free(&self) -> usize {
+        N - self.pipe.len()
+    }
+}
+
+#[cfg(target_has_atomic = "ptr")]
+impl<const N: usize> PipeWriter<'_, N> {
+    /// Attempts to write bytes from `buf`.
+    ///
+    /// If the pipe is full and `buf` isn't empty, this returns `Poll::Pending`, and the task is
+    /// woken once bytes have been read. Only the task of the last call is woken.
+    pub fn poll_write(&mut self, cx: &mut Context<'_>, buf: &[u8]) -> Poll<usize> {
+        let n = self.pipe.write_inner(buf);
+        if n != 0 || buf.is_empty() {
+            return Poll::Ready(n);
+        }
+        self.pipe.write_waker.register(cx.waker());
+        // Bytes may have been read before the waker was registered.
+        match self.pipe.write_inner(buf) {
+            0 => Poll::Pending,
+            n => Poll::Ready(n),
+        }
+    }
+}
+
+impl<const N: usize> ErrorType for PipeWriter<'_, N> {
+    type Error = SliceWriteError;
+}
+
+impl<const N: usize> Write for PipeWriter<'_, N> {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+        self.pipe.write_checked(buf)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+impl<const N: usize> WriteReady for PipeWriter<'_, N> {
+    #[inline]
+    fn write_ready(&mut self) -> Result<bool, Self::Error> {
+        Ok(true)
+    }
+}
+
+/// Slot for the waker of a task, which can be registered and woken from different contexts.
+///
+/// This is the algorithm of `futures`' `AtomicWaker`: `state` serializes the accesses to `waker`.
+#[cfg(target_has_atomic = "ptr")]
+struct WakerCell {
+    state: AtomicUsize,
+    waker: UnsafeCell<Option<Waker>>,
+}
+
+#[cfg(target_has_atomic = "ptr")]
+impl WakerCell {
+    /// Nobody is accessing the waker.
+    const WAITING: usize = 0;
+    /// The waker is being registered.
+    const REGISTERING: usize = 0b01;
+    /// The waker is being woken.
+    const WAKING: usize = 0b10;
+
+    const fn new() -> Self {
+        Self {
+            state: AtomicUsize::new(Self::WAITING),
+            waker: UnsafeCell::new(None),
+        }
+    }
+
+    fn register(&self, waker: &Waker) {
+        match self
+            .state
+            .compare_exchange(
+                Self::WAITING,
+                Self::REGISTERING,
+                Ordering::Acquire,
+                Ordering::Acquire,
+            )
+            .unwrap_or_else(|state| state)
+        {
+            Self::WAITING => {
+                // SAFETY: the `REGISTERING` state gives exclusive access to the waker.
+                let old = unsafe {
+                    let slot = &mut *self.waker.get();
+                    match slot {
+                        Some(old) if old.will_wake(waker) => None,
+                        _ => slot.replace(waker.clone()),
+                    }
+                };
+                if self
+                    .state
+                    .compare_exchange(
+                        Self::REGISTERING,
+                        Self::WAITING,
+                        Ordering::AcqRel,
+                        Ordering::Acquire,
+                    )
+                    .is_err()
+                {
+                    // `wake` was called concurrently, and left it to us: the state is
+                    // `REGISTERING | WAKING`, so we still have exclusive access.
+                    // SAFETY: see above.
+                    let waker = unsafe { (*self.waker.get()).take() };
+                    self.state.swap(Self::WAITING, Ordering::AcqRel);
+                    if let Some(waker) = waker {
+                        waker.wake();
+                    }
+                }
+                drop(old);
+            }
+            // Being woken concurrently: wake the new waker instead of registering it.
+            Self::WAKING => waker.wake_by_ref(),
+            // Registering concurrently, which the single reader or writer never does.
+            _ => {}
+        }
+    }
+
+    fn wake(&self) {
+        if self.state.fetch_or(Self::WAKING, Ordering::AcqRel) == Self::WAITING {
+            // SAFETY: the `WAKING` state gives exclusive access to the waker.
+            let waker = unsafe { (*self.waker.get()).take() };
+            self.state.fetch_and(!Self::WAKING, Ordering::Release);
+            if let Some(waker) = waker {
+                waker.wake();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn positions_wrap_around() {
+        let mut pipe = Pipe::<4>::new();
+        // Start close to the wrap-around of the positions.
+        pipe.read_pos.store(usize::MAX - 2, Ordering::Relaxed);
+        pipe.write_pos.store(usize::MAX - 2, Ordering::Relaxed);
+
+        let (mut reader, mut writer) = pipe.split();
+        let mut next_write = 0u8;
+        let mut next_read = 0u8;
+        for chunk in [3, 1, 4, 2, 3, 4, 1, 3] {
+            let data: [u8; 4] = core::array::from_fn(|i| next_write.wrapping_add(i as u8));
+            let written = writer.write(&data[..chunk]).unwrap();
+            next_write = next_write.wrapping_add(written as u8);
+
+            let mut buf = [0; 4];
+            let n = reader.read(&mut buf).unwrap();
+            assert_eq!(n, written);
+            for b in &buf[..n] {
+                assert_eq!(*b, next_read);
+                next_read = next_read.wrapping_add(1);
+            }
+        }
+        assert!(reader.is_empty());
+    }
+}
@@ -0,0 +1,132 @@
+use crate::{ErrorType, Read, ReadReady, Write, WriteReady};
+
+#[cfg(feature = "defmt-03")]
+use crate::defmt;
+
+/// Size of the stack buffer used by [`Pipeline::copy_with`].
+const BUF_SIZE: usize = 64;
+
+/// A reader and a writer combined into a single stream.
+///
+/// `Pipeline` implements [`Read`] by reading from the reader, and [`Write`] by writing to the
+/// writer, e.g. to forward data from a TCP socket to a UART. It can be wrapped by adapters
+/// observing the stream in both directions, and [`copy_with`](Pipeline::copy_with) copies data
+/// from the reader to the writer, letting middleware observe or transform each chunk.
+///
+/// ```
+/// use embedded_io::{Cursor, Pipeline};
+///
+/// let mut buf = [0u8; 8];
+/// let mut pipeline = Pipeline::new(Cursor::new(b"hello"), Cursor::new(&mut buf[..]));
+///
+/// let n = pipeline.copy_with(|chunk| chunk.make_ascii_uppercase()).unwrap();
+/// assert_eq!(n, 5);
+///
+/// let (_, writer) = pipeline.split();
+/// assert_eq!(writer.position(), 5);
+/// assert_eq!(&buf[..5], b"HELLO");
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub struct Pipeline<R, W> {
+    reader: R,
+    writer: W,
+}
+
+impl<R, W> Pipeline<R, W> {
+    /// Create a new `Pipeline` from a reader and a writer.
+    #[inline]
+    pub fn new(reader: R, writer: W) -> Self {
+        Self { reader, writer }
+    }
+
+    /// Consumes the `Pipeline`, returning the reader and the writer.
+    #[inline]
+    pub fn split(self) -> (R, W) {
+        (self.reader, self.writer)
+    }
+
+    /// Gets a reference to the reader.
+    #[inline]
+    pub fn reader(&self) -> &R {
+        &self.reader
+    }
+
+    /// Gets a mutable reference to the reader.
+    #[inline]
+    pub fn reader_mut(&mut self) -> &mut R {
+        &mut self.reader
+    }
+
+    /// Gets a reference to the writer.
+    #[inline]
+    pub fn writer(&self) -> &W {
+        &self.writer
+    }
+
+    /// Gets a mutable reference to the writer.
+    #[inline]
+    pub fn writer_mut(&mut self) -> &mut W {
+        &mut self.writer
+    }
+}
+
+impl<R: Read, W: Write<Error = R::Error>> Pipeline<R, W> {
+    /// Copies the entire contents of the reader into the writer, calling `f` on each chunk
+    /// before writing it.
+    ///
+    /// This reads until EOF, and returns the number of bytes copied. Data is copied through a
+    /// 64-byte buffer on the stack, and `f` may modify each chunk in place.
+    ///
+    /// `flush` is not called on the writer after the copy completes.
+    pub fn copy_with(&mut self, mut f: impl FnMut(&mut [u8])) -> Result<u64, R::Error> {
+        let mut buf = [0; BUF_SIZE];
+        let mut copied = 0;
+        loop {
+            let read = self.reader.read(&mut buf)?;
+            if read == 0 {
+                return Ok(copied);
+            }
+            f(&mut buf[..read]);
+            self.writer.write_all(&buf[..read])?;
+            copied += read as u64;
+        }
+    }
+}
+
+impl<R: ErrorType, W: ErrorType<Error = R::Error>> ErrorType for Pipeline<R, W> {
+    type Error = R::Error;
+}
+
+impl<R: Read, W: ErrorType<Error = R::Error>> Read for Pipeline<R, W> {
+    #[inline]
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        self.reader.read(buf)
+    }
+}
+
+impl<R: ReadReady, W: ErrorType<Error = R::Error>> ReadReady for Pipeline<R, W> {
+    #[inline]
+    fn read_ready(&mut self) -> Result<bool, Self::Error> {
+        self.reader.read_ready()
+    }
+}
+
+impl<R: ErrorType, W: Write<Error = R::Error>> Write for Pipeline<R, W> {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+        self.writer.write(buf)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        self.writer.flush()
+    }
+}
+
+impl<R: ErrorType, W: WriteReady<Error = R::Error>> WriteReady for Pipeline<R, W> {
+    #[inline]
+    fn write_ready(&mut self) -> Result<bool, Self::Error> {
+        self.writer.write_ready()
+    }
+}
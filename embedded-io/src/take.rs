@@ -0,0 +1,108 @@
+use core::cmp;
+
+use crate::{BufRead, ErrorType, Read, ReadReady};
+
+#[cfg(feature = "defmt-03")]
+use crate::defmt;
+
+/// Reader adapter which limits the bytes read from an underlying reader.
+///
+/// This struct is generally created by calling [`take`](Read::take) on a reader.
+/// Once `limit` bytes have been read, further reads return `Ok(0)` (EOF).
+/// If the underlying reader implements [`BufRead`], so does `Take`, and the buffer returned by
+/// `fill_buf` is limited as well.
+///
+/// This is the `embedded-io` equivalent of [`std::io::Take`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub struct Take<R> {
+    inner: R,
+    limit: u64,
+}
+
+impl<R> Take<R> {
+    pub(crate) fn new(inner: R, limit: u64) -> Self {
+        Self { inner, limit }
+    }
+
+    /// Returns the number of bytes that can be read before this instance returns EOF.
+    ///
+    /// This can be less than the number of bytes actually remaining in the underlying reader.
+    #[inline]
+    pub fn limit(&self) -> u64 {
+        self.limit
+    }
+
+    /// Sets the number of bytes that can be read before this instance returns EOF.
+    ///
+    /// This is the same as constructing a new `Take` instance, so the amount of bytes read
+    /// and the previous limit value don't matter.
+    #[inline]
+    pub fn set_limit(&mut self, limit: u64) {
+        self.limit = limit;
+    }
+
+    /// Consumes the `Take`, returning the wrapped reader.
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    /// Gets a reference to the underlying reader.
+    #[inline]
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the underlying reader.
+    ///
+    /// Care should be taken to avoid modifying the internal I/O state of the
+    /// underlying reader as doing so may corrupt the internal limit of this `Take`.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+}
+
+impl<R: ErrorType> ErrorType for Take<R> {
+    type Error = R::Error;
+}
+
+impl<R: Read> Read for Take<R> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        if self.limit == 0 {
+            return Ok(0);
+        }
+        let max = cmp::min(buf.len() as u64, self.limit) as usize;
+        let n = self.inner.read(&mut buf[..max])?;
+        self.limit -= n as u64;
+        Ok(n)
+    }
+}
+
+impl<R: BufRead> BufRead for Take<R> {
+    fn fill_buf(&mut self) -> Result<&[u8], Self::Error> {
+        if self.limit == 0 {
+            return Ok(&[]);
+        }
+        let buf = self.inner.fill_buf()?;
+        let max = cmp::min(buf.len() as u64, self.limit) as usize;
+        Ok(&buf[..max])
+    }
+
+    fn consume(&mut self, amt: usize) {
+        // Don't let callers reset the limit by passing an overlarge value
+        let amt = cmp::min(amt as u64, self.limit) as usize;
+        self.limit -= amt as u64;
+        self.inner.consume(amt);
+    }
+}
+
+impl<R: ReadReady> ReadReady for Take<R> {
+    fn read_ready(&mut self) -> Result<bool, Self::Error> {
+        if self.limit == 0 {
+            return Ok(true);
+        }
+        self.inner.read_ready()
+    }
+}